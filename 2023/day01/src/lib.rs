@@ -0,0 +1,55 @@
+use std::error::Error;
+use utils_2024::Solution;
+
+pub struct Day01;
+
+fn calibration_value(line: &str) -> usize {
+    let mut filtered = line.chars().filter(|c| c.is_ascii_digit());
+    let first = filtered.next().unwrap();
+    let last = filtered.next_back().unwrap_or(first);
+    format!("{}{}", first, last).parse::<usize>().unwrap()
+}
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> usize {
+        lines.iter().map(|line| calibration_value(line)).sum()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> usize {
+        lines
+            .iter()
+            .map(|line| {
+                let mut line = line.to_string();
+
+                let replacements = [
+                    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                ];
+
+                for (i, item) in replacements.iter().enumerate() {
+                    let mut chars = item.chars();
+                    let replacement = format!(
+                        "{}{}{}",
+                        chars.next().unwrap(),
+                        i + 1,
+                        chars.next_back().unwrap()
+                    );
+                    line = line.replace(replacements[i], &replacement);
+                }
+
+                calibration_value(&line)
+            })
+            .sum()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day01::solve(input)
+}
@@ -1,47 +1,11 @@
-use std::{fs::read_to_string, io::Error};
+use std::error::Error;
+use utils_2024::input;
 
-fn main() -> Result<(), Error> {
-    let input = read_to_string("input.txt")?;
-    let lines = input.lines();
-
-    let part1: usize = lines
-        .clone()
-        .map(|line| {
-            let mut filtered = line.chars().filter(|c| c.is_ascii_digit());
-            let first = filtered.next().unwrap();
-            let last = filtered.last().unwrap_or(first);
-            format!("{}{}", first, last).parse::<usize>().unwrap()
-        })
-        .sum();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = input::load(2023, 1)?;
+    let (part1, part2) = day01_2023::solve(&input)?;
 
     println!("Part 1: {}", part1);
-
-    let part2: usize = lines
-        .map(|line| {
-            let mut line = line.to_string();
-
-            let replacements = [
-                "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-            ];
-
-            for (i, item) in replacements.iter().enumerate() {
-                let mut chars = item.chars();
-                let replacement = format!(
-                    "{}{}{}",
-                    chars.next().unwrap(),
-                    i + 1,
-                    chars.last().unwrap()
-                );
-                line = line.replace(replacements[i], &replacement);
-            }
-
-            let mut filtered = line.chars().filter(|c| c.is_ascii_digit());
-            let first = filtered.next().unwrap();
-            let last = filtered.last().unwrap_or(first);
-            format!("{}{}", first, last).parse::<usize>().unwrap()
-        })
-        .sum();
-
     println!("Part 2: {}", part2);
 
     Ok(())
@@ -0,0 +1,44 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let numbers = input.get_lines().try_numbers_in_column::<usize>(b' ')?;
+
+        if numbers.len() != 2 {
+            return Err("There should be exactly two columns".into());
+        } else if numbers[0].len() != numbers[1].len() {
+            return Err("Both columns should have the same amount of numbers".into());
+        }
+
+        let mut left = numbers[0].clone();
+        let mut right = numbers[1].clone();
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input<'_>) -> usize {
+        left.iter()
+            .zip(right.iter())
+            .map(|(left_value, right_value)| left_value.abs_diff(*right_value))
+            .sum()
+    }
+
+    fn part2((left, right): &Self::Input<'_>) -> usize {
+        left.iter()
+            .map(|left_value| left_value * right.iter().filter(|&val| val == left_value).count())
+            .sum()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day01::solve(input)
+}
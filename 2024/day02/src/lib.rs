@@ -0,0 +1,81 @@
+use std::error::Error;
+use utils_2024::Solution;
+
+pub struct Day02;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Status {
+    Increasing,
+    Decreasing,
+}
+
+fn check_valid(list: &[i32]) -> bool {
+    let mut status: Option<Status> = None;
+    let mut previous: Option<i32> = None;
+
+    for &current in list {
+        match (status, previous, current) {
+            (_, None, _) => previous = Some(current),
+            (None, Some(prev_val), cur_val) => {
+                if prev_val == cur_val || (prev_val - cur_val).abs() > 3 {
+                    return false;
+                } else if prev_val < cur_val {
+                    status = Some(Status::Increasing);
+                } else {
+                    status = Some(Status::Decreasing);
+                }
+                previous = Some(cur_val);
+            }
+            (Some(status_val), Some(prev_val), cur_val) => {
+                if prev_val == cur_val
+                    || (prev_val - cur_val).abs() > 3
+                    || ((prev_val > cur_val) == (status_val == Status::Increasing))
+                {
+                    return false;
+                }
+                previous = Some(cur_val);
+            }
+        }
+    }
+    true
+}
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let mut reports = Vec::new();
+        for line in input.lines() {
+            let levels = line
+                .split_whitespace()
+                .map(|part| part.parse::<i32>())
+                .collect::<Result<Vec<i32>, _>>()?;
+            reports.push(levels);
+        }
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Input<'_>) -> usize {
+        reports.iter().filter(|parts| check_valid(parts)).count()
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> usize {
+        reports
+            .iter()
+            .filter(|parts| {
+                check_valid(parts)
+                    || (0..parts.len()).any(|i| {
+                        let mut parts_clone = parts.to_vec();
+                        parts_clone.remove(i);
+                        check_valid(&parts_clone)
+                    })
+            })
+            .count()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day02::solve(input)
+}
@@ -0,0 +1,65 @@
+use regex::Regex;
+use std::error::Error;
+use utils_2024::Solution;
+
+pub struct Day03;
+
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let mut instructions = Vec::new();
+
+        let re = Regex::new(r"(do\(\))|(don't\(\))|mul\(([0-9]{1,3}),([0-9]{1,3})\)")?;
+        for captures in re.captures_iter(input) {
+            if captures.get(1).is_some() {
+                instructions.push(Instruction::Do);
+            } else if captures.get(2).is_some() {
+                instructions.push(Instruction::Dont);
+            } else {
+                let nbr1 = captures.get(3).unwrap().as_str().parse::<i32>()?;
+                let nbr2 = captures.get(4).unwrap().as_str().parse::<i32>()?;
+                instructions.push(Instruction::Mul(nbr1, nbr2));
+            }
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> i32 {
+        instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(nbr1, nbr2) => nbr1 * nbr2,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> i32 {
+        let mut sum = 0;
+        let mut count_enabled = true;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => count_enabled = true,
+                Instruction::Dont => count_enabled = false,
+                Instruction::Mul(nbr1, nbr2) if count_enabled => sum += nbr1 * nbr2,
+                Instruction::Mul(_, _) => (),
+            }
+        }
+        sum
+    }
+}
+
+pub fn solve(input: &str) -> Result<(i32, i32), Box<dyn Error>> {
+    Day03::solve(input)
+}
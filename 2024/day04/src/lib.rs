@@ -0,0 +1,79 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day04;
+
+const DIRECTIONS: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(1, -1),
+    Point::new(-1, -1),
+    Point::new(-1, 1),
+];
+
+fn search_string(
+    input: &Grid<char>,
+    direction: Point,
+    position: Position,
+    search: &[char],
+    sum: &mut u32,
+) {
+    if search.is_empty() {
+        *sum += 1;
+        return;
+    }
+
+    if let Some(next) = input.offset(position, direction) {
+        if input[next] == search[0] {
+            search_string(input, direction, next, &search[1..], sum);
+        }
+    }
+}
+
+fn diagonal(input: &Grid<char>, position: Position, offsets: [Point; 2]) -> bool {
+    let letters = offsets.map(|offset| input.offset(position, offset).map(|pos| input[pos]));
+    matches!(letters, [Some('M'), Some('S')] | [Some('S'), Some('M')])
+}
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<char>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(Grid::from(input.get_lines().try_lines_as_chars()?))
+    }
+
+    fn part1(chars: &Self::Input<'_>) -> u32 {
+        let mut total = 0;
+        let haystack: Vec<char> = "MAS".chars().collect();
+
+        for (position, &c) in chars.iter() {
+            if c == 'X' {
+                for direction in DIRECTIONS {
+                    search_string(chars, direction, position, &haystack, &mut total);
+                }
+            }
+        }
+
+        total
+    }
+
+    fn part2(chars: &Self::Input<'_>) -> u32 {
+        chars
+            .iter()
+            .filter(|&(position, &c)| {
+                c == 'A'
+                    && diagonal(chars, position, [Point::new(-1, -1), Point::new(1, 1)])
+                    && diagonal(chars, position, [Point::new(1, -1), Point::new(-1, 1)])
+            })
+            .count() as u32
+    }
+}
+
+pub fn solve(input: &str) -> Result<(u32, u32), Box<dyn Error>> {
+    Day04::solve(input)
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day05;
+
+fn check_valid(rules: &[Vec<u32>], input: &[u32]) -> bool {
+    for i in 0..input.len() {
+        for rule in rules {
+            if rule[0] == input[i] {
+                for item in input.iter().take(i) {
+                    if rule[1] == *item {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+    true
+}
+
+fn check_valid_swap(rules: &[Vec<u32>], input: &mut [u32]) -> bool {
+    let mut sorted = true;
+    for i in 0..input.len() {
+        for rule in rules {
+            if rule[0] == input[i] {
+                for j in 0..i {
+                    if rule[1] == input[j] {
+                        input.swap(i, j);
+                        sorted = false;
+                    }
+                }
+            }
+        }
+    }
+    sorted
+}
+
+impl Solution for Day05 {
+    type Input<'a> = (Vec<Vec<u32>>, Vec<Vec<u32>>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let [rules, updates] = input
+            .sections()
+            .try_into()
+            .map_err(|_| "Expected page ordering rules and a list of updates")?;
+
+        let rules = rules
+            .lines()
+            .iter()
+            .map(|line| match line.numbers::<u32>(b'|')? {
+                rule if rule.len() == 2 => Ok(rule),
+                _ => Err(Errors::ParseError {
+                    line: line.number,
+                    column: 1,
+                    token: line.text.to_string(),
+                    expected: "two pages separated by `|`".to_string(),
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+        let updates = updates
+            .lines()
+            .iter()
+            .map(|line| line.numbers(b','))
+            .collect::<Result<_, _>>()?;
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, items): &Self::Input<'_>) -> u32 {
+        items
+            .iter()
+            .filter(|item| check_valid(rules, item))
+            .map(|item| item[item.len() / 2])
+            .sum()
+    }
+
+    fn part2((rules, items): &Self::Input<'_>) -> u32 {
+        let mut sum = 0;
+        for item in &mut items.clone() {
+            if !check_valid(rules, item) {
+                while !check_valid_swap(rules, item) {}
+                sum += item[item.len() / 2];
+            }
+        }
+        sum
+    }
+}
+
+pub fn solve(input: &str) -> Result<(u32, u32), Box<dyn Error>> {
+    Day05::solve(input)
+}
@@ -0,0 +1,260 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day06;
+
+/// The map of the lab, with a jump table holding for every cell and direction
+/// the cell where the guard stops in front of the next obstacle, or `None` if
+/// the guard leaves the map.
+pub struct Lab {
+    obstacles: Grid<bool>,
+    start: Position,
+    jumps: Grid<[Option<Position>; 4]>,
+    // The walk without additional obstacles, which has to leave the map.
+    walk: Path,
+}
+
+/// Every step of the guard: the position and the direction the guard faces there.
+pub type Path = Vec<(Position, Direction)>;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cycle {
+    /// The walk up to the first repeated step, ending with one round of the loop.
+    pub path: Path,
+    /// Index in `path` where the loop starts.
+    pub start: usize,
+}
+
+impl Cycle {
+    /// The first step that is part of the loop.
+    pub fn entry(&self) -> (Position, Direction) {
+        self.path[self.start]
+    }
+
+    /// Number of steps, turns included, until the guard repeats the entry step.
+    pub fn period(&self) -> usize {
+        self.path.len() - self.start
+    }
+
+    pub fn steps(&self) -> &[(Position, Direction)] {
+        &self.path[self.start..]
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum MapResult {
+    Infinite(Cycle),
+    Finished(Path),
+}
+
+// Positions of the path in the order the guard first visits them.
+fn distinct_positions(lab: &Lab, path: &[(Position, Direction)]) -> Vec<Position> {
+    let mut seen = lab.obstacles.map(|_| false);
+    path.iter()
+        .filter(|&&(position, _)| !std::mem::replace(&mut seen[position], true))
+        .map(|&(position, _)| position)
+        .collect()
+}
+
+fn mask(direction: Direction) -> u8 {
+    1 << direction as u8
+}
+
+fn jump_table(obstacles: &Grid<bool>) -> Grid<[Option<Position>; 4]> {
+    let mut jumps = Grid::new(obstacles.width(), obstacles.height(), [None; 4]);
+    let mut positions: Vec<Position> = obstacles.positions().collect();
+
+    for direction in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        // Cells further ahead need to be done first.
+        if direction == Direction::South {
+            positions.reverse();
+        }
+        for &position in &positions {
+            jumps[position][direction as usize] = match obstacles.offset(position, direction) {
+                None => None,
+                Some(next) if obstacles[next] => Some(position),
+                Some(next) => jumps[next][direction as usize],
+            };
+        }
+    }
+    jumps
+}
+
+// The cell in front of `obstacle` if the guard walks into it from `position`.
+fn stop_before(position: Position, direction: Direction, obstacle: Position) -> Option<Position> {
+    let ((y, x), (oy, ox)) = (position, obstacle);
+    match direction {
+        Direction::North if ox == x && oy < y => Some((oy + 1, ox)),
+        Direction::South if ox == x && oy > y => Some((oy - 1, ox)),
+        Direction::East if oy == y && ox > x => Some((oy, ox - 1)),
+        Direction::West if oy == y && ox < x => Some((oy, ox + 1)),
+        _ => None,
+    }
+}
+
+impl Lab {
+    /// Walks step by step, with an additional obstacle at `obstacle`.
+    pub fn run_simulation(&self, obstacle: Option<Position>) -> MapResult {
+        let mut visited = self.obstacles.map(|_| 0u8);
+        let mut direction = Direction::North;
+        let mut position = self.start;
+        let mut path = Vec::new();
+
+        loop {
+            if visited[position] & mask(direction) != 0 {
+                let start = path
+                    .iter()
+                    .position(|&step| step == (position, direction))
+                    .unwrap();
+                return MapResult::Infinite(Cycle { path, start });
+            }
+            visited[position] |= mask(direction);
+            path.push((position, direction));
+
+            let Some(next) = self.obstacles.offset(position, direction) else {
+                return MapResult::Finished(path);
+            };
+
+            if self.obstacles[next] || Some(next) == obstacle {
+                direction = direction.turn_right();
+            } else {
+                position = next;
+            }
+        }
+    }
+
+    // Jumps from obstacle to obstacle and only remembers the cells where the
+    // guard turns, which is enough to recognise a loop.
+    fn loops_with(&self, obstacle: Position) -> bool {
+        let mut turns = Grid::new(self.obstacles.width(), self.obstacles.height(), 0u8);
+        let mut direction = Direction::North;
+        let mut position = self.start;
+
+        loop {
+            let jump = self.jumps[position][direction as usize];
+            let stop = match (jump, stop_before(position, direction, obstacle)) {
+                (Some(jump), Some(blocked)) => [blocked, jump]
+                    .into_iter()
+                    .min_by_key(|&(y, x)| y.abs_diff(position.0) + x.abs_diff(position.1)),
+                (jump, blocked) => blocked.or(jump),
+            };
+            let Some(stop) = stop else {
+                return false;
+            };
+
+            if turns[stop] & mask(direction) != 0 {
+                return true;
+            }
+            turns[stop] |= mask(direction);
+            position = stop;
+            direction = direction.turn_right();
+        }
+    }
+
+    // An additional obstacle only matters on the path, and can't go on the start.
+    fn candidates(&self) -> Vec<Position> {
+        distinct_positions(self, &self.walk)
+            .into_iter()
+            .filter(|&position| position != self.start)
+            .collect()
+    }
+
+    /// Every position on the guard's path where an additional obstacle makes
+    /// the guard walk in a loop, along with that loop.
+    pub fn loops(&self) -> Vec<(Position, Cycle)> {
+        Executor::new().map_reduce(
+            &self.candidates(),
+            Vec::new,
+            |&obstacle| match self.loops_with(obstacle) {
+                true => match self.run_simulation(Some(obstacle)) {
+                    MapResult::Infinite(cycle) => vec![(obstacle, cycle)],
+                    MapResult::Finished(_) => unreachable!("Both simulations have to agree"),
+                },
+                false => Vec::new(),
+            },
+            |mut loops, other| {
+                loops.extend(other);
+                loops
+            },
+        )
+    }
+
+    /// Draws `path` over the map like the puzzle does, with `|` and `-` for
+    /// vertical and horizontal moves, `+` where those meet and `O` for an
+    /// additional obstacle.
+    pub fn render(&self, path: &[(Position, Direction)], obstacle: Option<Position>) -> String {
+        let mut moves = self.obstacles.map(|_| [false; 2]);
+        for &(position, direction) in path {
+            moves[position][direction.is_vertical() as usize] = true;
+        }
+
+        let mut output = String::new();
+        for y in 0..self.obstacles.height() {
+            for x in 0..self.obstacles.width() {
+                output.push(match (y, x) {
+                    position if Some(position) == obstacle => 'O',
+                    position if position == self.start => '^',
+                    position if self.obstacles[position] => '#',
+                    position => match moves[position] {
+                        [true, true] => '+',
+                        [false, true] => '|',
+                        [true, false] => '-',
+                        [false, false] => '.',
+                    },
+                });
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Solution for Day06 {
+    type Input<'a> = Lab;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let map = Grid::from(input.get_lines().try_lines_as_chars()?);
+        let start = map
+            .position(|&field| field == '^')
+            .ok_or("Unable to find start position")?;
+        let obstacles = map.map(|&field| field == '#');
+        let jumps = jump_table(&obstacles);
+
+        let mut lab = Lab {
+            obstacles,
+            start,
+            jumps,
+            walk: Vec::new(),
+        };
+        lab.walk = match lab.run_simulation(None) {
+            MapResult::Finished(path) => path,
+            MapResult::Infinite(_) => return Err("The guard never leaves the map".into()),
+        };
+
+        Ok(lab)
+    }
+
+    fn part1(lab: &Self::Input<'_>) -> usize {
+        distinct_positions(lab, &lab.walk).len()
+    }
+
+    fn part2(lab: &Self::Input<'_>) -> usize {
+        Executor::new().map_reduce(
+            &lab.candidates(),
+            || 0,
+            |&field| lab.loops_with(field) as usize,
+            |a, b| a + b,
+        )
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day06::solve(input)
+}
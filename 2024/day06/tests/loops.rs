@@ -0,0 +1,80 @@
+use day06_2024::{Day06, Lab, MapResult};
+use utils_2024::{Direction, Solution};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+fn lab() -> Lab {
+    Day06::parse(EXAMPLE).unwrap()
+}
+
+#[test]
+fn finds_the_example_loops() {
+    let mut obstacles: Vec<_> = lab()
+        .loops()
+        .into_iter()
+        .map(|(obstacle, _)| obstacle)
+        .collect();
+    obstacles.sort_unstable();
+
+    assert_eq!(obstacles, [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]);
+}
+
+#[test]
+fn reports_the_cycle() {
+    let lab = lab();
+    let MapResult::Infinite(cycle) = lab.run_simulation(Some((6, 3))) else {
+        panic!("Expected the guard to walk in a loop");
+    };
+
+    assert_eq!(cycle.start, 0);
+    assert_eq!(cycle.entry(), ((6, 4), Direction::North));
+    assert_eq!(cycle.period(), 22);
+    assert_eq!(cycle.steps(), &cycle.path[..]);
+    assert_eq!(
+        lab.render(&cycle.path, Some((6, 3))),
+        "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#...
+"
+    );
+}
+
+#[test]
+fn renders_the_walk() {
+    let lab = lab();
+    let MapResult::Finished(path) = lab.run_simulation(None) else {
+        panic!("Expected the guard to leave the map");
+    };
+
+    assert_eq!(
+        lab.render(&path, None),
+        "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+-^-+-+.
+.+----++#.
+#+----+|..
+......#|..
+"
+    );
+}
+
+#[test]
+fn rejects_a_guard_that_never_leaves() {
+    let Err(err) = Day06::parse(".#.\n#^#\n.#.\n") else {
+        panic!("Expected the boxed in guard to be rejected");
+    };
+    assert_eq!(err.to_string(), "The guard never leaves the map");
+}
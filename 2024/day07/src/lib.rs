@@ -0,0 +1,90 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day07;
+
+pub struct InputLine {
+    key: i128,
+    list: Vec<i128>,
+}
+
+// `left` followed by the digits of `right`, if that is a number that fits.
+fn concat(left: i128, right: i128) -> Option<i128> {
+    format!("{left}{right}").parse().ok()
+}
+
+fn calculate_result(input: &InputLine, current_value: i128, position: usize, part2: bool) -> bool {
+    if position == input.list.len() {
+        return current_value == input.key;
+    }
+
+    calculate_result(
+        input,
+        current_value + input.list[position],
+        position + 1,
+        part2,
+    ) || calculate_result(
+        input,
+        current_value * input.list[position],
+        position + 1,
+        part2,
+    ) || (part2
+        && concat(current_value, input.list[position])
+            .is_some_and(|value| calculate_result(input, value, position + 1, part2)))
+}
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<InputLine>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let lines = input.get_lines();
+        let equations = lines.try_split_with_key::<i128, i128>(':')?;
+
+        // `try_split_with_key` skips blank lines, so skip them here as well.
+        let numbered = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        for ((number, line), (_, list)) in numbered.zip(&equations) {
+            if list.is_empty() {
+                return Err(Errors::ParseError {
+                    line: number + 1,
+                    column: line.chars().count() + 1,
+                    token: String::new(),
+                    expected: "numbers after `:`".to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(equations
+            .into_iter()
+            .map(|(key, list)| InputLine { key, list })
+            .collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i128 {
+        input
+            .iter()
+            .filter(|item| calculate_result(item, item.list[0], 1, false))
+            .map(|item| item.key)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i128 {
+        input
+            .iter()
+            .filter(|item| {
+                calculate_result(item, item.list[0], 1, false)
+                    || calculate_result(item, item.list[0], 1, true)
+            })
+            .map(|item| item.key)
+            .sum()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(i128, i128), Box<dyn Error>> {
+    Day07::solve(input)
+}
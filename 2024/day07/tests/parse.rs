@@ -0,0 +1,26 @@
+use day07_2024::Day07;
+use utils_2024::{Errors, Solution};
+
+#[test]
+fn rejects_equations_without_numbers() {
+    let Err(err) = Day07::parse("190: 10 19\n\n190:\n") else {
+        panic!("Expected the equation to be rejected");
+    };
+
+    assert_eq!(
+        *err.downcast::<Errors>().unwrap(),
+        Errors::ParseError {
+            line: 3,
+            column: 5,
+            token: String::new(),
+            expected: "numbers after `:`".to_string(),
+        }
+    );
+}
+
+#[test]
+fn skips_concatenations_that_are_no_number() {
+    let equations = Day07::parse("53: 5 -3\n").unwrap();
+
+    assert_eq!(Day07::part2(&equations), 0);
+}
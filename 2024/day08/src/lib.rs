@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day08;
+
+fn check_position(map: &mut Grid<bool>, point: Point) -> Option<bool> {
+    let position = Position::try_from(point).ok()?;
+
+    let antinode = map.get_mut(position)?;
+    if !*antinode {
+        *antinode = true;
+        Some(true)
+    } else {
+        Some(false)
+    }
+}
+
+fn searching(map: &mut Grid<bool>, start: Point, diff: Point, part2: bool) -> usize {
+    let mut total_antinodes = 0;
+    let mut point = start;
+
+    while let Some(result) = check_position(map, point) {
+        if result {
+            total_antinodes += 1;
+        }
+        point += diff;
+        if !part2 {
+            return total_antinodes;
+        }
+    }
+    total_antinodes
+}
+
+fn calculation(map: &mut Grid<bool>, groups: &HashMap<char, Vec<Position>>, part2: bool) -> usize {
+    let mut total_antinodes = 0;
+
+    for group in groups.values() {
+        for (i, &item) in group.iter().enumerate() {
+            for &other in &group[i + 1..] {
+                let first = Point::from(item);
+                let second = Point::from(other);
+                let diff = first - second;
+
+                if !part2 {
+                    total_antinodes += searching(map, first + diff, diff, part2);
+                    total_antinodes += searching(map, second - diff, -diff, part2);
+                } else {
+                    total_antinodes += searching(map, first, diff, part2);
+                    total_antinodes += searching(map, second, -diff, part2);
+                }
+            }
+        }
+    }
+    total_antinodes
+}
+
+impl Solution for Day08 {
+    type Input<'a> = (Grid<bool>, HashMap<char, Vec<Position>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let chars = Grid::from(input.get_lines().try_lines_as_chars()?);
+
+        let groups = chars
+            .iter()
+            .filter(|(_, &character)| character != '.')
+            .fold(
+                HashMap::new(),
+                |mut items: HashMap<char, Vec<Position>>, (position, &key)| {
+                    items.entry(key).or_default().push(position);
+                    items
+                },
+            );
+
+        Ok((chars.map(|_| false), groups))
+    }
+
+    fn part1((map, groups): &Self::Input<'_>) -> usize {
+        calculation(&mut map.clone(), groups, false)
+    }
+
+    fn part2((map, groups): &Self::Input<'_>) -> usize {
+        calculation(&mut map.clone(), groups, true)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day08::solve(input)
+}
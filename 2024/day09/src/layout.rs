@@ -0,0 +1,144 @@
+use crate::ItemType;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+use utils_2024::Errors;
+
+/// Disk layout in the puzzle's notation, like `00...111...2...333.44.5555`.
+/// File ids from 10 to 35 are shown as `a` to `z`, larger ones as `?`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout(pub Vec<ItemType>);
+
+impl Deref for Layout {
+    type Target = Vec<ItemType>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Layout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for item in self.iter() {
+            let (c, width) = match *item {
+                ItemType::File(id, width) => {
+                    let c = u32::try_from(id)
+                        .ok()
+                        .and_then(|id| char::from_digit(id, 36))
+                        .unwrap_or('?');
+                    (c, width)
+                }
+                ItemType::Space(width) => ('.', width),
+            };
+            for _ in 0..width {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Layout {
+    type Err = Errors;
+
+    /// Reads runs of the same character as one item, so neighbouring parts of
+    /// the same file come back merged.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items: Vec<ItemType> = Vec::new();
+
+        for (column, c) in s.trim_end().chars().enumerate() {
+            let item = match c {
+                '.' => ItemType::Space(1),
+                c => match c.to_digit(36) {
+                    Some(id) if !c.is_ascii_uppercase() => ItemType::File(id as usize, 1),
+                    _ => {
+                        return Err(Errors::ParseError {
+                            line: 1,
+                            column: column + 1,
+                            token: c.to_string(),
+                            expected: "file id or `.`".to_string(),
+                        })
+                    }
+                },
+            };
+
+            match (items.last_mut(), item) {
+                (Some(ItemType::Space(width)), ItemType::Space(_)) => *width += 1,
+                (Some(ItemType::File(last, width)), ItemType::File(id, _)) if *last == id => {
+                    *width += 1
+                }
+                (_, item) => items.push(item),
+            }
+        }
+        Ok(Layout(items))
+    }
+}
+
+fn blocks(chars: &[char]) -> Vec<Option<usize>> {
+    chars
+        .iter()
+        .enumerate()
+        .flat_map(|(total, c)| {
+            let amount = c.to_digit(10).unwrap() as usize;
+            let block = (total % 2 == 0).then_some(total / 2);
+            (0..amount).map(move |_| block)
+        })
+        .collect()
+}
+
+fn layout(blocks: &[Option<usize>]) -> Layout {
+    let items = blocks
+        .chunk_by(|a, b| a == b)
+        .map(|run| match run[0] {
+            Some(id) => ItemType::File(id, run.len()),
+            None => ItemType::Space(run.len()),
+        })
+        .collect();
+    Layout(items)
+}
+
+/// The layout before compacting and after every single block that part 1
+/// moves. Works on every block, so it's only meant for small inputs.
+pub fn block_steps(chars: &[char]) -> Vec<Layout> {
+    let mut blocks = blocks(chars);
+    let mut steps = vec![layout(&blocks)];
+
+    loop {
+        let free = blocks.iter().position(Option::is_none);
+        let last = blocks.iter().rposition(Option::is_some);
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => {
+                blocks.swap(free, last);
+                steps.push(layout(&blocks));
+            }
+            _ => return steps,
+        }
+    }
+}
+
+/// The layout before compacting and after every file that part 2 moves,
+/// again only meant for small inputs.
+pub fn file_steps(chars: &[char]) -> Vec<Layout> {
+    let mut blocks = blocks(chars);
+    let mut steps = vec![layout(&blocks)];
+
+    for id in (0..chars.len().div_ceil(2)).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let size = blocks[start..]
+            .iter()
+            .take_while(|&&block| block == Some(id))
+            .count();
+
+        let free = blocks[..start]
+            .windows(size)
+            .position(|window| window.iter().all(Option::is_none));
+        if let Some(free) = free {
+            blocks[free..free + size].fill(Some(id));
+            blocks[start..start + size].fill(None);
+            steps.push(layout(&blocks));
+        }
+    }
+    steps
+}
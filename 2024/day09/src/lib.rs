@@ -0,0 +1,156 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use utils_2024::*;
+pub mod layout;
+
+pub struct Day09;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItemType {
+    File(usize, usize),
+    Space(usize),
+}
+
+// Start block and length of every file in id order, and of the free span
+// following each of them.
+struct Disk {
+    files: Vec<(usize, usize)>,
+    spaces: Vec<(usize, usize)>,
+}
+
+fn layout(chars: &[char]) -> Disk {
+    let mut disk = Disk {
+        files: Vec::new(),
+        spaces: Vec::new(),
+    };
+    let mut position = 0;
+
+    for (total, c) in chars.iter().enumerate() {
+        let amount = c.to_digit(10).unwrap() as usize;
+        if total % 2 == 0 {
+            disk.files.push((position, amount));
+        } else {
+            disk.spaces.push((position, amount));
+        }
+        position += amount;
+    }
+    disk
+}
+
+// Fills the free spans from the left with blocks taken from the end of the
+// last file, then the one before, until both ends meet.
+fn move_one_block(disk: &Disk) -> Vec<ItemType> {
+    let mut items = Vec::new();
+    let Some(mut right) = disk.files.len().checked_sub(1) else {
+        return items;
+    };
+    let mut remaining = disk.files[right].1;
+
+    for (id, &(_, size)) in disk.files.iter().enumerate() {
+        if id == right {
+            items.push(ItemType::File(id, remaining));
+            break;
+        }
+        items.push(ItemType::File(id, size));
+
+        let mut space = disk.spaces.get(id).map_or(0, |&(_, size)| size);
+        while space > 0 && right > id {
+            if remaining == 0 {
+                right -= 1;
+                remaining = disk.files[right].1;
+                continue;
+            }
+            let moved = space.min(remaining);
+            items.push(ItemType::File(right, moved));
+            space -= moved;
+            remaining -= moved;
+        }
+        if right <= id {
+            break;
+        }
+    }
+    items
+}
+
+// Keeps the start of the free spans of every size in a min-heap, so the
+// leftmost span that fits a file is the smallest top of the heaps for its size
+// and above.
+fn move_whole_items(disk: &Disk) -> Vec<ItemType> {
+    let mut free: [BinaryHeap<Reverse<usize>>; 10] = Default::default();
+    for &(start, size) in &disk.spaces {
+        free[size].push(Reverse(start));
+    }
+
+    let mut files = Vec::with_capacity(disk.files.len());
+    for (id, &(start, size)) in disk.files.iter().enumerate().rev() {
+        let span = (size.max(1)..free.len())
+            .filter_map(|span| free[span].peek().map(|&Reverse(position)| (position, span)))
+            .filter(|&(position, _)| position < start)
+            .min();
+
+        match span {
+            Some((position, span)) => {
+                free[span].pop();
+                free[span - size].push(Reverse(position + size));
+                files.push((position, id, size));
+            }
+            None => files.push((start, id, size)),
+        }
+    }
+    files.sort_unstable();
+
+    let mut items = Vec::with_capacity(files.len() * 2);
+    let mut position = 0;
+    for (start, id, size) in files {
+        if start > position {
+            items.push(ItemType::Space(start - position));
+        }
+        items.push(ItemType::File(id, size));
+        position = start + size;
+    }
+    items
+}
+
+fn calculate_result(items: &[ItemType]) -> usize {
+    let mut result = 0;
+    let mut realindex = 0;
+
+    for item in items {
+        if let ItemType::File(index, width) = item {
+            for _ in 0..*width {
+                result += realindex * index;
+                realindex += 1;
+            }
+        } else if let ItemType::Space(width) = item {
+            realindex += width;
+        }
+    }
+    result
+}
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let chars = input.get_chars_trimmed();
+        if let Some(c) = chars.iter().find(|c| !c.is_ascii_digit()) {
+            return Err(format!("Invalid disk map character: {c}").into());
+        }
+        Ok(chars)
+    }
+
+    fn part1(chars: &Self::Input<'_>) -> usize {
+        calculate_result(&move_one_block(&layout(chars)))
+    }
+
+    fn part2(chars: &Self::Input<'_>) -> usize {
+        calculate_result(&move_whole_items(&layout(chars)))
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day09::solve(input)
+}
@@ -0,0 +1,65 @@
+use day09_2024::layout::{block_steps, file_steps, Layout};
+use day09_2024::ItemType;
+
+const EXAMPLE: &str = "2333133121414131402";
+
+// The longest disk map the binary shows step by step, with file ids 0 to 35.
+fn longest_map() -> Vec<char> {
+    (0..71)
+        .map(|i| char::from(b'1' + (i * 7 % 9) as u8))
+        .collect()
+}
+
+fn assert_round_trip(steps: &[Layout]) {
+    for step in steps {
+        let rendered = step.to_string();
+        assert!(!rendered.contains('?'), "{rendered}");
+        assert_eq!(&rendered.parse::<Layout>().unwrap(), step, "{rendered}");
+    }
+}
+
+#[test]
+fn renders_the_puzzle_example() {
+    let chars: Vec<char> = EXAMPLE.chars().collect();
+
+    assert_eq!(
+        block_steps(&chars).last().unwrap().to_string(),
+        "0099811188827773336446555566.............."
+    );
+    assert_eq!(
+        file_steps(&chars).last().unwrap().to_string(),
+        "00992111777.44.333....5555.6666.....8888.."
+    );
+}
+
+#[test]
+fn parses_rendered_steps_back() {
+    let chars: Vec<char> = EXAMPLE.chars().collect();
+    assert_round_trip(&block_steps(&chars));
+    assert_round_trip(&file_steps(&chars));
+
+    let chars = longest_map();
+    assert_round_trip(&block_steps(&chars));
+    assert_round_trip(&file_steps(&chars));
+}
+
+#[test]
+fn parses_runs_into_items() {
+    let layout: Layout = "00..z1".parse().unwrap();
+
+    assert_eq!(
+        *layout,
+        [
+            ItemType::File(0, 2),
+            ItemType::Space(2),
+            ItemType::File(35, 1),
+            ItemType::File(1, 1),
+        ]
+    );
+}
+
+#[test]
+fn rejects_unknown_characters() {
+    assert!("00?11".parse::<Layout>().is_err());
+    assert!("00A11".parse::<Layout>().is_err());
+}
@@ -0,0 +1,54 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day10;
+
+fn search_path(input: &mut Grid<u8>, position: Position, value: u8, part1: bool) -> u32 {
+    if value == 9 {
+        if part1 {
+            input[position] = 0;
+        }
+        return 1;
+    }
+
+    let expected = value + 1;
+
+    let next: Vec<Position> = input
+        .neighbours(position)
+        .filter(|&neighbour| input[neighbour] == expected)
+        .collect();
+
+    next.into_iter()
+        .map(|neighbour| search_path(input, neighbour, expected, part1))
+        .sum()
+}
+
+fn trailheads(input: &Grid<u8>, part1: bool) -> u32 {
+    input
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(position, _)| search_path(&mut input.clone(), position, 0, part1))
+        .sum()
+}
+
+impl Solution for Day10 {
+    type Input<'a> = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(Grid::from(input.get_lines().try_digits_grid()?))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        trailheads(input, true)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        trailheads(input, false)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(u32, u32), Box<dyn Error>> {
+    Day10::solve(input)
+}
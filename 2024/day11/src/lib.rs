@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::hash::Hash;
+use std::ops::{Div, Rem};
+use utils_2024::{Errors, Solution};
+
+pub struct Day11;
+
+// Stone numbers and counts; blinking starts with `u64` and switches to `u128`
+// once either overflows.
+trait Number: Copy + Eq + Hash + From<u16> + Div<Output = Self> + Rem<Output = Self> {
+    fn digits(self) -> u32;
+    fn power_of_ten(exponent: u32) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! number {
+    ($($type:ty),+) => {
+        $(
+            impl Number for $type {
+                fn digits(self) -> u32 {
+                    self.checked_ilog10().map_or(1, |log| log + 1)
+                }
+
+                fn power_of_ten(exponent: u32) -> Self {
+                    <$type>::pow(10, exponent)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$type>::checked_mul(self, other)
+                }
+            }
+        )+
+    };
+}
+
+number!(u64, u128);
+
+fn add<T: Number>(stones: &mut HashMap<T, T>, stone: T, count: T) -> Option<()> {
+    let total = stones.entry(stone).or_insert(T::from(0));
+    *total = total.checked_add(count)?;
+    Some(())
+}
+
+// How often every number shows up after one more blink.
+fn blink_once<T: Number>(stones: &HashMap<T, T>) -> Option<HashMap<T, T>> {
+    let mut next = HashMap::with_capacity(stones.len() * 2);
+
+    for (&stone, &count) in stones {
+        let digits = stone.digits();
+        if stone == T::from(0) {
+            add(&mut next, T::from(1), count)?;
+        } else if digits % 2 == 0 {
+            let half = T::power_of_ten(digits / 2);
+            add(&mut next, stone / half, count)?;
+            add(&mut next, stone % half, count)?;
+        } else {
+            add(&mut next, stone.checked_mul(T::from(2024))?, count)?;
+        }
+    }
+    Some(next)
+}
+
+/// Number of stones after some blinks, and how many different numbers are
+/// engraved on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stones {
+    pub total: u128,
+    pub distinct: usize,
+}
+
+fn try_blink<T: Number + Into<u128>>(input: &[u64], blinks: usize) -> Option<Stones>
+where
+    u64: Into<T>,
+{
+    let mut stones = HashMap::new();
+    for &stone in input {
+        add(&mut stones, stone.into(), T::from(1))?;
+    }
+    for _ in 0..blinks {
+        stones = blink_once(&stones)?;
+    }
+
+    let total = stones
+        .values()
+        .try_fold(T::from(0), |total, &count| total.checked_add(count))?;
+    Some(Stones {
+        total: total.into(),
+        distinct: stones.len(),
+    })
+}
+
+pub fn blink(input: &[u64], blinks: usize) -> Result<Stones, Errors> {
+    try_blink::<u64>(input, blinks)
+        .or_else(|| try_blink::<u128>(input, blinks))
+        .ok_or(Errors::IntegerOverflow)
+}
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<u64>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input
+            .split_whitespace()
+            .map(|number| number.parse::<u64>())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u128 {
+        blink(input, 25).unwrap().total
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u128 {
+        blink(input, 75).unwrap().total
+    }
+}
+
+pub fn solve(input: &str) -> Result<(u128, u128), Box<dyn Error>> {
+    Day11::solve(input)
+}
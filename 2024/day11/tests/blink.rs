@@ -0,0 +1,34 @@
+use day11_2024::{blink, Stones};
+use utils_2024::Errors;
+
+const EXAMPLE: [u64; 2] = [125, 17];
+
+#[test]
+fn counts_the_puzzle_example() {
+    assert_eq!(blink(&EXAMPLE, 6).unwrap().total, 22);
+    assert_eq!(blink(&EXAMPLE, 25).unwrap().total, 55312);
+}
+
+#[test]
+fn counts_past_u64_with_u128() {
+    let within_u64 = blink(&EXAMPLE, 100).unwrap();
+    assert_eq!(within_u64.total, 2266558877486382721);
+    assert!(within_u64.total <= u64::MAX.into());
+
+    assert_eq!(
+        blink(&EXAMPLE, 150).unwrap(),
+        Stones {
+            total: 2705183445934430257146293156,
+            distinct: 54,
+        }
+    );
+    assert_eq!(
+        blink(&EXAMPLE, 200).unwrap().total,
+        3228697720950807773236428359413636851
+    );
+}
+
+#[test]
+fn reports_overflowing_counts() {
+    assert_eq!(blink(&EXAMPLE, 250), Err(Errors::IntegerOverflow));
+}
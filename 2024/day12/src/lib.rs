@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::ops::AddAssign;
+use utils_2024::*;
+
+pub struct Day12;
+
+struct Plant(char, bool);
+
+impl PartialEq for Plant {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+struct Values(u64, u64, u64);
+
+impl From<Values> for (u64, u64, u64) {
+    fn from(value: Values) -> Self {
+        (value.0, value.1, value.2)
+    }
+}
+
+impl AddAssign for Values {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+        self.1 += other.1;
+        self.2 += other.2;
+    }
+}
+
+fn is_perimeter(map: &Grid<Plant>, position: Position, direction: Direction) -> bool {
+    map.offset(position, direction)
+        .is_none_or(|neighbour| map[neighbour] != map[position])
+}
+
+fn diagonal_corners(map: &Grid<Plant>, (y, x): Position, offset: Point) -> bool {
+    let Some((new_y, new_x)) = map.offset((y, x), offset) else {
+        return false;
+    };
+
+    map[(new_y, x)] != map[(y, x)]
+        && map[(y, new_x)] == map[(y, x)]
+        && map[(new_y, new_x)] == map[(y, x)]
+}
+
+fn perimeter_corner_count(map: &Grid<Plant>, position: Position) -> (u64, u64) {
+    let top = is_perimeter(map, position, Direction::North);
+    let bottom = is_perimeter(map, position, Direction::South);
+    let left = is_perimeter(map, position, Direction::West);
+    let right = is_perimeter(map, position, Direction::East);
+
+    let perimeters = [top, bottom, left, right]
+        .iter()
+        .filter(|&&val| val)
+        .count() as u64;
+
+    let mut corners = (top && left) as u64
+        + (top && right) as u64
+        + (right && bottom) as u64
+        + (left && bottom) as u64;
+
+    if (1..=3).contains(&perimeters) {
+        corners += diagonal_corners(map, position, Point::new(-1, -1)) as u64;
+        corners += diagonal_corners(map, position, Point::new(-1, 1)) as u64;
+        corners += diagonal_corners(map, position, Point::new(1, -1)) as u64;
+        corners += diagonal_corners(map, position, Point::new(1, 1)) as u64;
+    }
+
+    (perimeters, corners)
+}
+
+fn calculate(map: &mut Grid<Plant>, position: Position) -> Values {
+    if map[position].1 {
+        return Values(0, 0, 0);
+    }
+
+    map[position].1 = true;
+
+    let (perimeters, corners) = perimeter_corner_count(map, position);
+    let mut values = Values(1, perimeters, corners);
+
+    let neighbours: Vec<Position> = map
+        .neighbours(position)
+        .filter(|&neighbour| map[neighbour] == map[position])
+        .collect();
+
+    for neighbour in neighbours {
+        values += calculate(map, neighbour);
+    }
+
+    values
+}
+
+fn calculate_regions(input: &Grid<char>) -> (u64, u64) {
+    let mut map = input.map(|&c| Plant(c, false));
+
+    let mut part1 = 0;
+    let mut part2 = 0;
+
+    for position in input.positions() {
+        if map[position].1 {
+            continue;
+        }
+        let (area, perimeters, sides) = calculate(&mut map, position).into();
+        part1 += area * perimeters;
+        part2 += area * sides;
+    }
+
+    (part1, part2)
+}
+
+impl Solution for Day12 {
+    type Input<'a> = Grid<char>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(Grid::from(input.get_lines().try_lines_as_chars()?))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        calculate_regions(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        calculate_regions(input).1
+    }
+}
+
+pub fn solve(input: &str) -> Result<(u64, u64), Box<dyn Error>> {
+    Day12::solve(input)
+}
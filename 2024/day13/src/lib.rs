@@ -0,0 +1,85 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day13;
+
+pub struct Machine {
+    button_a: [isize; 2],
+    button_b: [isize; 2],
+    prize: [isize; 2],
+}
+
+fn calculate(ax: isize, ay: isize, bx: isize, by: isize, px: isize, py: isize) -> Option<isize> {
+    let determinant = ax * by - bx * ay;
+
+    if determinant == 0 {
+        return None;
+    }
+
+    let mut button_a = px * by - py * bx;
+    let mut button_b = ax * py - ay * px;
+
+    if button_a % determinant != 0 || button_b % determinant != 0 {
+        return None;
+    }
+
+    button_a /= determinant;
+    button_b /= determinant;
+
+    Some(button_a * 3 + button_b)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, Errors> {
+    let button_a = Template::new("Button A: X+{}, Y+{}");
+    let button_b = Template::new("Button B: X+{}, Y+{}");
+    let prize = Template::new("Prize: X={}, Y={}");
+
+    input
+        .sections()
+        .iter()
+        .map(|section| {
+            let [a, b, p] = section.exact_lines()?;
+            Ok(Machine {
+                button_a: button_a.parse(a)?,
+                button_b: button_b.parse(b)?,
+                prize: prize.parse(p)?,
+            })
+        })
+        .collect()
+}
+
+fn total_tokens(machines: &[Machine], offset: isize) -> isize {
+    let mut total = 0;
+    for machine in machines {
+        let [ax, ay] = machine.button_a;
+        let [bx, by] = machine.button_b;
+        let [px, py] = machine.prize.map(|value| value + offset);
+
+        if let Some(tokens) = calculate(ax, ay, bx, by, px, py) {
+            total += tokens;
+        }
+    }
+    total
+}
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Machine>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(machines: &Self::Input<'_>) -> isize {
+        total_tokens(machines, 0)
+    }
+
+    fn part2(machines: &Self::Input<'_>) -> isize {
+        total_tokens(machines, 10000000000000)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(isize, isize), Box<dyn Error>> {
+    Day13::solve(input)
+}
@@ -0,0 +1,175 @@
+use std::error::Error;
+use utils_2024::*;
+
+/// Robots moving on a `WIDTH` by `HEIGHT` grid, which is 11 by 7 in the example.
+pub struct Day14<const WIDTH: i32 = 101, const HEIGHT: i32 = 103>;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: (i32, i32),
+    velocities: (i32, i32),
+}
+
+fn render_map(map: &[Robot], width: i32, height: i32) -> String {
+    let mut grid = vec![vec![0; width as usize]; height as usize];
+
+    for robot in map {
+        grid[robot.position.1 as usize][robot.position.0 as usize] += 1;
+    }
+
+    let mut output = String::new();
+    for line in grid {
+        for value in line {
+            if value == 0 {
+                output.push('.');
+            } else {
+                output.push_str(&value.to_string());
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn find_diagonal(map: &[Robot], x: i32, y: i32, count: i32, stop: i32) -> bool {
+    let mut left_found = false;
+    let mut right_found = false;
+
+    for robot in map {
+        if robot.position.1 == y + count {
+            if robot.position.0 == x - count {
+                left_found = true;
+            } else if robot.position.0 == x + count {
+                right_found = true;
+            }
+        }
+    }
+
+    if !left_found || !right_found {
+        return false;
+    }
+
+    if count != stop {
+        return find_diagonal(map, x, y, count + 1, stop);
+    }
+    true
+}
+
+fn find_tree(map: &[Robot]) -> bool {
+    for robot in map {
+        if find_diagonal(map, robot.position.0, robot.position.1, 1, 5) {
+            return true;
+        }
+    }
+    false
+}
+
+fn parse_map(input: &str) -> Result<Vec<Robot>, Errors> {
+    let robot = Template::new("p={},{} v={},{}");
+
+    input
+        .sections()
+        .iter()
+        .flat_map(|section| section.lines())
+        .map(|&line| {
+            let [x, y, dx, dy] = robot.parse(line)?;
+            Ok(Robot {
+                position: (x, y),
+                velocities: (dx, dy),
+            })
+        })
+        .collect()
+}
+
+fn robo_move(robot: &mut Robot, width: i32, height: i32) {
+    robot.position.0 += robot.velocities.0;
+    if robot.position.0 < 0 {
+        robot.position.0 += width;
+    } else if robot.position.0 >= width {
+        robot.position.0 -= width;
+    }
+
+    robot.position.1 += robot.velocities.1;
+    if robot.position.1 < 0 {
+        robot.position.1 += height;
+    } else if robot.position.1 >= height {
+        robot.position.1 -= height;
+    }
+}
+
+fn safety_factor(map: &mut [Robot], width: i32, height: i32) -> usize {
+    for _ in 0..100 {
+        for robot in &mut *map {
+            robo_move(robot, width, height);
+        }
+    }
+
+    let mut topleft = 0;
+    let mut topright = 0;
+    let mut bottomleft = 0;
+    let mut bottomright = 0;
+
+    for robot in map {
+        if robot.position.0 < width / 2 && robot.position.1 < height / 2 {
+            topleft += 1;
+        } else if robot.position.0 < width / 2 && robot.position.1 > height / 2 {
+            bottomleft += 1;
+        } else if robot.position.0 > width / 2 && robot.position.1 < height / 2 {
+            topright += 1;
+        } else if robot.position.0 > width / 2 && robot.position.1 > height / 2 {
+            bottomright += 1;
+        }
+    }
+
+    topleft * topright * bottomleft * bottomright
+}
+
+// The robots are back at their start after `width * height` seconds, so
+// there is no tree if none showed up by then.
+fn seconds_until_tree(map: &mut [Robot], width: i32, height: i32) -> Option<usize> {
+    for i in 0..(width * height) as usize {
+        for robot in &mut *map {
+            robo_move(robot, width, height);
+        }
+        if find_tree(map) {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+impl<const WIDTH: i32, const HEIGHT: i32> Day14<WIDTH, HEIGHT> {
+    /// Draws the robots after `seconds`, with the number of robots on every
+    /// occupied tile.
+    pub fn render_after(robots: &[Robot], seconds: usize) -> String {
+        let mut map = robots.to_vec();
+        for _ in 0..seconds {
+            for robot in &mut map {
+                robo_move(robot, WIDTH, HEIGHT);
+            }
+        }
+        render_map(&map, WIDTH, HEIGHT)
+    }
+}
+
+impl<const WIDTH: i32, const HEIGHT: i32> Solution for Day14<WIDTH, HEIGHT> {
+    type Input<'a> = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(robots: &Self::Input<'_>) -> usize {
+        safety_factor(&mut robots.clone(), WIDTH, HEIGHT)
+    }
+
+    fn part2(robots: &Self::Input<'_>) -> MaybeAnswer<usize> {
+        seconds_until_tree(&mut robots.clone(), WIDTH, HEIGHT).into()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, MaybeAnswer<usize>), Box<dyn Error>> {
+    <Day14>::solve(input)
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day15;
+
+fn simulate(map: &mut Grid<char>, moves: &[Direction]) -> usize {
+    let mut position = map.find(&'@').unwrap();
+    for &direction in moves {
+        if !direction.is_vertical() {
+            move_horizontal(map, &mut position, direction);
+        } else if check_vertical(map, position, direction) {
+            move_vertical(map, position, direction);
+            position = map.offset(position, direction).unwrap();
+        }
+    }
+
+    calculate_total(map)
+}
+
+fn check_vertical(map: &Grid<char>, position: Position, direction: Direction) -> bool {
+    let (y, x) = map.offset(position, direction).unwrap();
+
+    match map[(y, x)] {
+        '[' => check_vertical(map, (y, x), direction) && check_vertical(map, (y, x + 1), direction),
+        ']' => check_vertical(map, (y, x), direction) && check_vertical(map, (y, x - 1), direction),
+        'O' => check_vertical(map, (y, x), direction),
+        '.' => true,
+        _ => false,
+    }
+}
+
+fn move_vertical(map: &mut Grid<char>, position: Position, direction: Direction) {
+    let (y, x) = map.offset(position, direction).unwrap();
+
+    match map[(y, x)] {
+        '[' => {
+            move_vertical(map, (y, x), direction);
+            move_vertical(map, (y, x + 1), direction);
+        }
+        ']' => {
+            move_vertical(map, (y, x - 1), direction);
+            move_vertical(map, (y, x), direction);
+        }
+        'O' => move_vertical(map, (y, x), direction),
+        _ => {}
+    }
+
+    map[(y, x)] = map[position];
+    map[position] = '.';
+}
+
+fn move_horizontal(map: &mut Grid<char>, position: &mut Position, direction: Direction) {
+    let mut tmp = map.offset(*position, direction).unwrap();
+
+    let chars = ['O', '[', ']'];
+    while chars.contains(&map[tmp]) {
+        tmp = map.offset(tmp, direction).unwrap();
+    }
+
+    if map[tmp] != '.' {
+        return;
+    }
+
+    while tmp != *position {
+        let previous = map.offset(tmp, direction.opposite()).unwrap();
+        map[tmp] = map[previous];
+        tmp = previous;
+        map[tmp] = '.';
+    }
+    *position = map.offset(*position, direction).unwrap()
+}
+
+fn calculate_total(map: &Grid<char>) -> usize {
+    map.iter()
+        .filter(|(_, &c)| c == '[' || c == 'O')
+        .map(|((y, x), _)| y * 100 + x)
+        .sum()
+}
+
+fn part2_map(map: &Grid<char>) -> Grid<char> {
+    let mut new_map = Vec::new();
+
+    for line in map.rows() {
+        let mut map_line = Vec::new();
+        for c in line {
+            match c {
+                '#' => map_line.extend_from_slice(&['#', '#']),
+                'O' => map_line.extend_from_slice(&['[', ']']),
+                '.' => map_line.extend_from_slice(&['.', '.']),
+                _ => map_line.extend_from_slice(&['@', '.']),
+            }
+        }
+        new_map.push(map_line);
+    }
+
+    Grid::from(new_map)
+}
+
+fn invalid(line: &Line, column: usize, c: char, expected: &str) -> Errors {
+    Errors::ParseError {
+        line: line.number,
+        column: column + 1,
+        token: c.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+// Checks the characters of the map, which needs walls all around so that
+// neither the robot nor the boxes can leave it.
+fn check_map(map: &Section) -> Result<(), Errors> {
+    let last = map.lines().len() - 1;
+
+    for (y, line) in map.lines().iter().enumerate() {
+        let width = line.text.chars().count();
+        for (x, c) in line.text.chars().enumerate() {
+            if y == 0 || y == last || x == 0 || x + 1 == width {
+                if c != '#' {
+                    return Err(invalid(line, x, c, "`#` around the map"));
+                }
+            } else if !"#O.@".contains(c) {
+                return Err(invalid(line, x, c, "one of `#O.@`"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Vec<Direction>), Box<dyn Error>> {
+    let [map, moves] = input
+        .sections()
+        .try_into()
+        .map_err(|_| "Expected a map and a list of moves")?;
+
+    check_map(&map)?;
+    let map = map.grid()?;
+
+    let mut directions = Vec::new();
+    for line in moves.lines() {
+        for (column, c) in line.text.chars().enumerate() {
+            let direction = Direction::from_arrow(c)
+                .ok_or_else(|| invalid(line, column, c, "one of `^>v<`"))?;
+            directions.push(direction);
+        }
+    }
+
+    Ok((map, directions))
+}
+
+impl Solution for Day15 {
+    type Input<'a> = (Grid<char>, Vec<Direction>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let (map, moves) = parse_input(input)?;
+        if map.find(&'@').is_none() {
+            return Err("Unable to find the robot".into());
+        }
+        Ok((map, moves))
+    }
+
+    fn part1((map, moves): &Self::Input<'_>) -> usize {
+        simulate(&mut map.clone(), moves)
+    }
+
+    fn part2((map, moves): &Self::Input<'_>) -> usize {
+        simulate(&mut part2_map(map), moves)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day15::solve(input)
+}
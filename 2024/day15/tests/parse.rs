@@ -0,0 +1,48 @@
+use day15_2024::Day15;
+use utils_2024::{Errors, Solution};
+
+fn parse_error(input: &str) -> Errors {
+    let Err(err) = Day15::parse(input) else {
+        panic!("Expected the input to be rejected");
+    };
+    *err.downcast::<Errors>().unwrap()
+}
+
+#[test]
+fn rejects_unknown_map_characters() {
+    assert_eq!(
+        parse_error("#####\n#.@X#\n#####\n\n<>\n"),
+        Errors::ParseError {
+            line: 2,
+            column: 4,
+            token: "X".to_string(),
+            expected: "one of `#O.@`".to_string(),
+        }
+    );
+}
+
+#[test]
+fn rejects_maps_without_walls_around_them() {
+    assert_eq!(
+        parse_error("#####\n.O@.#\n#####\n\n<<\n"),
+        Errors::ParseError {
+            line: 2,
+            column: 1,
+            token: ".".to_string(),
+            expected: "`#` around the map".to_string(),
+        }
+    );
+}
+
+#[test]
+fn rejects_unknown_moves() {
+    assert_eq!(
+        parse_error("#####\n#.@.#\n#####\n\n<>\n^x\n"),
+        Errors::ParseError {
+            line: 6,
+            column: 2,
+            token: "x".to_string(),
+            expected: "one of `^>v<`".to_string(),
+        }
+    );
+}
@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day16;
+
+fn search_path(
+    map: &Grid<char>,
+    (y, x): Position,
+    direction: Direction,
+    cost: usize,
+    cache: &mut HashMap<(usize, usize, bool), usize>,
+) -> Option<(usize, HashSet<Position>)> {
+    let vertical = direction.is_vertical();
+
+    if map[(y, x)] == '#'
+        || cache
+            .get(&(y, x, vertical))
+            .is_some_and(|prevcost| *prevcost < cost)
+    {
+        return None;
+    } else if map[(y, x)] == 'E' {
+        return Some((cost, HashSet::from([(y, x)])));
+    }
+
+    cache.insert((y, x, vertical), cost);
+
+    let next = map.offset((y, x), direction).unwrap();
+    let mut lowest = search_path(map, next, direction, cost + 1, cache);
+
+    for direction in [direction.turn_left(), direction.turn_right()] {
+        if let Some(result) = search_path(map, (y, x), direction, cost + 1000, cache) {
+            match &mut lowest {
+                Some(low) => match low.0.cmp(&result.0) {
+                    std::cmp::Ordering::Greater => *low = result,
+                    std::cmp::Ordering::Equal => low.1.extend(result.1),
+                    std::cmp::Ordering::Less => (),
+                },
+                None => lowest = Some(result),
+            }
+        }
+    }
+
+    if let Some(low) = &mut lowest {
+        low.1.extend([(y, x)]);
+    }
+
+    lowest
+}
+
+fn best_paths(map: &Grid<char>) -> Option<(usize, HashSet<Position>)> {
+    let start = map.find(&'S').unwrap();
+
+    let mut cache: HashMap<(usize, usize, bool), usize> = HashMap::new();
+
+    search_path(map, start, Direction::East, 0, &mut cache)
+}
+
+// The maze needs walls all around, so that the search never leaves it, and a
+// single start and end tile.
+fn check_map(map: &Grid<char>) -> Result<(), Box<dyn Error>> {
+    for ((y, x), &c) in map.iter() {
+        let border = y == 0 || x == 0 || y + 1 == map.height() || x + 1 == map.width();
+        let expected = match border {
+            true if c != '#' => "`#` around the map",
+            _ if !"#.SE".contains(c) => "one of `#.SE`",
+            _ => continue,
+        };
+        return Err(Errors::ParseError {
+            line: y + 1,
+            column: x + 1,
+            token: c.to_string(),
+            expected: expected.to_string(),
+        }
+        .into());
+    }
+
+    for tile in ['S', 'E'] {
+        let count = map.iter().filter(|&(_, &c)| c == tile).count();
+        if count != 1 {
+            return Err(format!("Expected one `{tile}` tile, found {count}").into());
+        }
+    }
+    Ok(())
+}
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<char>;
+    type Part1 = MaybeAnswer<usize>;
+    type Part2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let map = Grid::from(input.get_lines().try_lines_as_chars()?);
+        check_map(&map)?;
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> MaybeAnswer<usize> {
+        best_paths(map).map(|(cost, _)| cost).into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> MaybeAnswer<usize> {
+        best_paths(map).map(|(_, tiles)| tiles.len()).into()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(MaybeAnswer<usize>, MaybeAnswer<usize>), Box<dyn Error>> {
+    Day16::solve(input)
+}
@@ -0,0 +1,35 @@
+use day16_2024::Day16;
+use utils_2024::{Errors, MaybeAnswer, Solution};
+
+#[test]
+fn rejects_mazes_without_walls_around_them() {
+    let Err(err) = Day16::parse("####\n#S.#\n#.E.\n####\n") else {
+        panic!("Expected the maze to be rejected");
+    };
+
+    assert_eq!(
+        *err.downcast::<Errors>().unwrap(),
+        Errors::ParseError {
+            line: 3,
+            column: 4,
+            token: ".".to_string(),
+            expected: "`#` around the map".to_string(),
+        }
+    );
+}
+
+#[test]
+fn rejects_mazes_without_an_end() {
+    let Err(err) = Day16::parse("####\n#S.#\n####\n") else {
+        panic!("Expected the maze to be rejected");
+    };
+    assert_eq!(err.to_string(), "Expected one `E` tile, found 0");
+}
+
+#[test]
+fn reports_unreachable_ends() {
+    let maze = Day16::parse("#####\n#S#E#\n#####\n").unwrap();
+
+    assert_eq!(Day16::part1(&maze), MaybeAnswer(None));
+    assert_eq!(Day16::part2(&maze), MaybeAnswer(None));
+}
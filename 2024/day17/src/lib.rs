@@ -0,0 +1,301 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+use utils_2024::*;
+pub mod machine;
+pub mod symbolic;
+use crate::machine::*;
+
+pub struct Day17;
+
+#[derive(PartialEq, Clone)]
+pub struct Instructions(Vec<usize>);
+
+impl Deref for Instructions {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Instructions {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let formatted = self
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", formatted)
+    }
+}
+
+// Bounds the runs of the part 2 searches, which try many register values.
+const STEP_LIMIT: usize = 1 << 20;
+
+// Bounds the run of the input program, to report an infinite loop rather than
+// hang. Programs that halt only loop while A has bits left, so they stay far
+// below this.
+const RUN_STEP_LIMIT: usize = 1 << 26;
+
+/// Output of the program for the initial `registers`, as part 1 runs it.
+pub fn run_program(
+    registers: [usize; 3],
+    instructions: &Instructions,
+) -> Result<Instructions, MachineError> {
+    let mut machine = Machine::new(registers, instructions).step_limit(RUN_STEP_LIMIT);
+    machine.run()?;
+    Ok(Instructions(machine.output))
+}
+
+// Output for a register A value tried by the searches. Runs that fail or take
+// too long output nothing, which never matches a program.
+fn calculation(register_a: usize, instructions: &Instructions) -> Instructions {
+    let mut machine = Machine::new([register_a, 0, 0], instructions).step_limit(STEP_LIMIT);
+    match machine.run() {
+        Ok(()) => Instructions(machine.output),
+        Err(_) => Instructions(Vec::new()),
+    }
+}
+
+fn get_start_number(instructions: &Instructions) -> Option<usize> {
+    let mut min = 0;
+    let mut max = usize::MAX;
+    let mut current = min / 2 + max / 2;
+
+    while min + 1 < max {
+        let output = calculation(current, instructions);
+        if output.len() < instructions.len() {
+            min = current;
+        } else if output.len() >= instructions.len() {
+            max = current;
+        }
+        current = min / 2 + max / 2;
+    }
+
+    let search_length = usize::min(instructions.len(), 7);
+    // Values past `8 * current` give longer outputs for the programs this
+    // search is meant for.
+    Executor::new().find_min(current + 1..current.saturating_mul(8), |i| {
+        let output = calculation(i, instructions);
+        output.len() >= search_length && output[0..search_length] == instructions[0..search_length]
+    })
+}
+
+// Steps up from `start` until the output matches, and starts over with half
+// the step size once the output gets too long.
+fn find_match(instructions: &Instructions, start: usize, step: usize) -> Option<usize> {
+    let mut current = start;
+    let mut step = step;
+
+    while step > 0 {
+        let result = calculation(current, instructions);
+        if result == *instructions {
+            return Some(current);
+        } else if result.len() > instructions.len() {
+            current = start;
+            step /= 2;
+        } else {
+            current = current.checked_add(step)?;
+        }
+    }
+    None
+}
+
+fn find_lower_number(instructions: &Instructions, start: usize, step: usize) -> usize {
+    const CANDIDATES: usize = 100000;
+
+    // Candidate `i` is `start - (CANDIDATES - i) * step`, so the smallest
+    // matching candidate is also the lowest register value.
+    let lowest = Executor::new()
+        .find_min(0..CANDIDATES, |i| {
+            (CANDIDATES - i)
+                .checked_mul(step)
+                .and_then(|offset| start.checked_sub(offset))
+                .is_some_and(|current| calculation(current, instructions) == *instructions)
+        })
+        .map_or(start, |i| start - (CANDIDATES - i) * step);
+
+    if step > 16384 {
+        return find_lower_number(instructions, lowest, step / 2);
+    }
+    lowest
+}
+
+/// Old bisection search, kept to cross-check `find_quine`. It may miss the
+/// smallest value.
+pub fn find_quine_heuristic(instructions: &Instructions) -> Option<usize> {
+    let start = get_start_number(instructions)?;
+    let step = 134217728; // 16384 * 8192
+
+    let lowest = find_match(instructions, start, step)?;
+    Some(find_lower_number(instructions, lowest, step / 2))
+}
+
+// Whether the program is a single loop that outputs one value, drops the
+// lowest three bits of A and jumps back to the start while A isn't 0, with B
+// and C only depending on A within an iteration. The output for A is then the
+// value for the lowest bits followed by the output for `A >> 3`.
+fn is_octal_loop(instructions: &Instructions) -> bool {
+    let Ok(body) = (0..instructions.len())
+        .step_by(2)
+        .map(|address| Instruction::decode(instructions, address))
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return false;
+    };
+    let Some((Instruction::Jnz(0), body)) = body.split_last() else {
+        return false;
+    };
+
+    let mut written = [true, false, false];
+    let mut shifts = 0;
+    let mut outputs = 0;
+    for instruction in body {
+        let (reads, writes) = match *instruction {
+            Instruction::Adv(Combo::Literal(3)) => {
+                shifts += 1;
+                (vec![], None)
+            }
+            Instruction::Adv(_) | Instruction::Jnz(_) => return false,
+            Instruction::Bxl(_) => (vec![Register::B], Some(Register::B)),
+            Instruction::Bst(combo) => (combo_reads(combo), Some(Register::B)),
+            Instruction::Bxc => (vec![Register::B, Register::C], Some(Register::B)),
+            Instruction::Out(combo) => {
+                outputs += 1;
+                (combo_reads(combo), None)
+            }
+            Instruction::Bdv(combo) => (combo_reads(combo), Some(Register::B)),
+            Instruction::Cdv(combo) => (combo_reads(combo), Some(Register::C)),
+        };
+        if reads.iter().any(|&register| !written[register as usize]) {
+            return false;
+        }
+        if let Some(register) = writes {
+            written[register as usize] = true;
+        }
+    }
+    shifts == 1 && outputs == 1
+}
+
+fn combo_reads(combo: Combo) -> Vec<Register> {
+    match combo {
+        Combo::Literal(_) => vec![],
+        Combo::Register(register) => vec![register],
+    }
+}
+
+// Builds A three bits at a time, starting with the bits that produce the last
+// output. Digits are tried in ascending order, so the first seed that
+// reproduces the whole program is the smallest one.
+fn reverse_search(instructions: &Instructions, index: usize, seed: usize) -> Option<usize> {
+    (0..8).find_map(|digit| {
+        let candidate = seed.checked_mul(8)? + digit;
+        let output = calculation(candidate, instructions);
+        if output[..] != instructions[index..] {
+            return None;
+        }
+        match index {
+            0 => Some(candidate),
+            _ => reverse_search(instructions, index - 1, candidate),
+        }
+    })
+}
+
+/// Smallest value for register A that makes the program output itself, if
+/// the program has the shape `reverse_search` relies on.
+pub fn find_quine(instructions: &Instructions) -> Option<usize> {
+    if instructions.is_empty() || !is_octal_loop(instructions) {
+        return None;
+    }
+    reverse_search(instructions, instructions.len() - 1, 0)
+}
+
+/// Registers and program, without running the program.
+pub fn parse_program(input: &str) -> Result<([usize; 3], Instructions), Box<dyn Error>> {
+    let register = Template::new("Register {}: {}");
+    let program = Template::new("Program: {}");
+
+    let [registers, instructions] = input
+        .sections()
+        .try_into()
+        .map_err(|_| "Expected three registers and a program")?;
+
+    let mut values = [0; 3];
+    for (line, (value, name)) in registers
+        .exact_lines::<3>()?
+        .into_iter()
+        .zip(values.iter_mut().zip(["A", "B", "C"]))
+    {
+        let [field, number] = register.captures(line)?;
+        if field.text != name {
+            return Err(Errors::ParseError {
+                line: field.line,
+                column: field.column,
+                token: field.text.to_string(),
+                expected: format!("register {name}"),
+            }
+            .into());
+        }
+        *value = number.parse()?;
+    }
+
+    let [line] = instructions.exact_lines()?;
+    let [field] = program.captures(line)?;
+    let mut column = field.column;
+    let instructions = field
+        .text
+        .split(',')
+        .map(|text| {
+            let number = Field {
+                column,
+                text,
+                ..field
+            };
+            column += text.len() + 1;
+            number.parse()
+        })
+        .collect::<Result<_, _>>()?;
+
+    // Jumps can still land on odd addresses, but every instruction at an even
+    // address has to be valid up front.
+    let instructions = Instructions(instructions);
+    for address in (0..instructions.len()).step_by(2) {
+        Instruction::decode(&instructions, address)?;
+    }
+
+    Ok((values, instructions))
+}
+
+pub struct Computer {
+    pub registers: [usize; 3],
+    pub program: Instructions,
+}
+
+impl Solution for Day17 {
+    type Input<'a> = Computer;
+    type Part1 = MaybeAnswer<Instructions>;
+    type Part2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let (registers, program) = parse_program(input)?;
+        Ok(Computer { registers, program })
+    }
+
+    // Fails like `run_program` if the program doesn't halt.
+    fn part1(computer: &Self::Input<'_>) -> MaybeAnswer<Instructions> {
+        run_program(computer.registers, &computer.program)
+            .ok()
+            .into()
+    }
+
+    fn part2(computer: &Self::Input<'_>) -> MaybeAnswer<usize> {
+        find_quine(&computer.program).into()
+    }
+}
+
+pub fn solve(
+    input: &str,
+) -> Result<(MaybeAnswer<Instructions>, MaybeAnswer<usize>), Box<dyn Error>> {
+    Day17::solve(input)
+}
@@ -0,0 +1,258 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combo {
+    Literal(usize),
+    Register(Register),
+}
+
+impl Combo {
+    fn decode(operand: usize) -> Option<Self> {
+        match operand {
+            0..=3 => Some(Combo::Literal(operand)),
+            4 => Some(Combo::Register(Register::A)),
+            5 => Some(Combo::Register(Register::B)),
+            6 => Some(Combo::Register(Register::C)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Combo::Literal(value) => write!(f, "{value}"),
+            Combo::Register(register) => write!(f, "{register:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Adv(Combo),
+    Bxl(usize),
+    Bst(Combo),
+    Jnz(usize),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+impl Instruction {
+    /// Decodes the instruction at `address`, which needs both its opcode and
+    /// its operand.
+    pub fn decode(program: &[usize], address: usize) -> Result<Self, MachineError> {
+        let (Some(&opcode), Some(&operand)) = (program.get(address), program.get(address + 1))
+        else {
+            return Err(MachineError::MissingOperand { address });
+        };
+        if operand > 7 {
+            return Err(MachineError::InvalidOperand { address, operand });
+        }
+        let combo =
+            || Combo::decode(operand).ok_or(MachineError::InvalidComboOperand { address, operand });
+
+        Ok(match opcode {
+            0 => Instruction::Adv(combo()?),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(combo()?),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(combo()?),
+            6 => Instruction::Bdv(combo()?),
+            7 => Instruction::Cdv(combo()?),
+            _ => return Err(MachineError::InvalidOpcode { address, opcode }),
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Instruction::Adv(combo) => write!(f, "adv {combo}"),
+            Instruction::Bxl(literal) => write!(f, "bxl {literal}"),
+            Instruction::Bst(combo) => write!(f, "bst {combo}"),
+            Instruction::Jnz(literal) => write!(f, "jnz {literal}"),
+            Instruction::Bxc => write!(f, "bxc"),
+            Instruction::Out(combo) => write!(f, "out {combo}"),
+            Instruction::Bdv(combo) => write!(f, "bdv {combo}"),
+            Instruction::Cdv(combo) => write!(f, "cdv {combo}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineError {
+    InvalidOpcode { address: usize, opcode: usize },
+    InvalidOperand { address: usize, operand: usize },
+    InvalidComboOperand { address: usize, operand: usize },
+    MissingOperand { address: usize },
+    StepLimit { limit: usize },
+}
+
+impl Display for MachineError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MachineError::InvalidOpcode { address, opcode } => {
+                write!(f, "Invalid opcode {opcode} at address {address}")
+            }
+            MachineError::InvalidOperand { address, operand } => {
+                write!(f, "Invalid 3-bit operand {operand} at address {address}")
+            }
+            MachineError::InvalidComboOperand { address, operand } => {
+                write!(f, "Invalid combo operand {operand} at address {address}")
+            }
+            MachineError::MissingOperand { address } => {
+                write!(f, "Missing operand for the opcode at address {address}")
+            }
+            MachineError::StepLimit { limit } => {
+                write!(f, "Program didn't halt within {limit} steps")
+            }
+        }
+    }
+}
+
+impl Error for MachineError {}
+
+/// One executed instruction, with the registers after executing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub address: usize,
+    pub instruction: Instruction,
+    pub registers: [usize; 3],
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let [a, b, c] = self.registers;
+        write!(
+            f,
+            "{:02}: {:<8} A={a} B={b} C={c}",
+            self.address,
+            self.instruction.to_string()
+        )
+    }
+}
+
+/// Lists the instructions at every even address. Addresses that can't be
+/// decoded show the error instead.
+pub fn disassemble(program: &[usize]) -> Vec<String> {
+    (0..program.len())
+        .step_by(2)
+        .map(|address| match Instruction::decode(program, address) {
+            Ok(instruction) => format!("{address:02}: {instruction}"),
+            Err(err) => format!("{address:02}: <{err}>"),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine<'p> {
+    pub registers: [usize; 3],
+    pub instruction_pointer: usize,
+    pub output: Vec<usize>,
+    program: &'p [usize],
+    steps: usize,
+    step_limit: Option<usize>,
+}
+
+impl<'p> Machine<'p> {
+    pub fn new(registers: [usize; 3], program: &'p [usize]) -> Self {
+        Self {
+            registers,
+            instruction_pointer: 0,
+            output: Vec::new(),
+            program,
+            steps: 0,
+            step_limit: None,
+        }
+    }
+
+    /// Makes `step` fail once `limit` instructions have been executed.
+    pub fn step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.instruction_pointer >= self.program.len()
+    }
+
+    fn combo(&self, combo: Combo) -> usize {
+        match combo {
+            Combo::Literal(value) => value,
+            Combo::Register(register) => self.registers[register as usize],
+        }
+    }
+
+    // `A / 2^combo`, which is 0 once the shift exceeds the register width.
+    fn divide(&self, combo: Combo) -> usize {
+        u32::try_from(self.combo(combo))
+            .ok()
+            .and_then(|shift| self.registers[0].checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    /// Executes a single instruction. Returns `None` once the machine halted.
+    pub fn step(&mut self) -> Result<Option<Step>, MachineError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+        if let Some(limit) = self.step_limit {
+            if self.steps >= limit {
+                return Err(MachineError::StepLimit { limit });
+            }
+        }
+
+        let address = self.instruction_pointer;
+        let instruction = Instruction::decode(self.program, address)?;
+        self.instruction_pointer += 2;
+        self.steps += 1;
+
+        match instruction {
+            Instruction::Adv(combo) => self.registers[0] = self.divide(combo),
+            Instruction::Bxl(literal) => self.registers[1] ^= literal,
+            Instruction::Bst(combo) => self.registers[1] = self.combo(combo) % 8,
+            Instruction::Jnz(literal) => {
+                if self.registers[0] != 0 {
+                    self.instruction_pointer = literal;
+                }
+            }
+            Instruction::Bxc => self.registers[1] ^= self.registers[2],
+            Instruction::Out(combo) => self.output.push(self.combo(combo) % 8),
+            Instruction::Bdv(combo) => self.registers[1] = self.divide(combo),
+            Instruction::Cdv(combo) => self.registers[2] = self.divide(combo),
+        }
+
+        Ok(Some(Step {
+            address,
+            instruction,
+            registers: self.registers,
+        }))
+    }
+
+    /// Runs until the machine halts, calling `trace` after every instruction.
+    pub fn run_traced(&mut self, mut trace: impl FnMut(&Step)) -> Result<(), MachineError> {
+        while let Some(step) = self.step()? {
+            trace(&step);
+        }
+        Ok(())
+    }
+
+    pub fn run(&mut self) -> Result<(), MachineError> {
+        self.run_traced(|_| ())
+    }
+}
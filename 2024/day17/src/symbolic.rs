@@ -0,0 +1,208 @@
+use crate::machine::{Combo, Instruction, MachineError};
+use std::fmt::{self, Display, Formatter};
+
+const WIDTH: usize = usize::BITS as usize;
+
+// A bit is either known, or depends on the bits of the initial register A in
+// its mask. Dependencies over-approximate, so `x ^ x` still depends on `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bit {
+    Const(bool),
+    Depends(u64),
+}
+
+impl Bit {
+    fn mask(self) -> u64 {
+        match self {
+            Bit::Const(_) => 0,
+            Bit::Depends(mask) => mask,
+        }
+    }
+
+    fn xor(self, other: Bit) -> Bit {
+        match (self, other) {
+            (Bit::Const(a), Bit::Const(b)) => Bit::Const(a ^ b),
+            (a, b) => Bit::Depends(a.mask() | b.mask()),
+        }
+    }
+}
+
+/// Bit-vector expression for the value of a register in terms of the bits of
+/// the initial register A.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Value([Bit; WIDTH]);
+
+impl Value {
+    fn constant(value: usize) -> Self {
+        Self(std::array::from_fn(|i| Bit::Const(value >> i & 1 == 1)))
+    }
+
+    fn mask(&self) -> u64 {
+        self.0.iter().fold(0, |mask, bit| mask | bit.mask())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&bit| bit == Bit::Const(false))
+    }
+
+    fn xor(&self, other: &Value) -> Value {
+        Self(std::array::from_fn(|i| self.0[i].xor(other.0[i])))
+    }
+
+    fn modulo_8(&self) -> Value {
+        Self(std::array::from_fn(|i| match i {
+            0..=2 => self.0[i],
+            _ => Bit::Const(false),
+        }))
+    }
+
+    // Every value the bits of `self` can take, or `None` if there are too many.
+    fn possible_values(&self) -> Option<Vec<usize>> {
+        let unknown: Vec<usize> = (0..WIDTH)
+            .filter(|&i| matches!(self.0[i], Bit::Depends(_)))
+            .collect();
+        if unknown.len() > 8 {
+            return None;
+        }
+
+        let known = (0..WIDTH).fold(0, |value, i| match self.0[i] {
+            Bit::Const(true) => value | 1 << i,
+            _ => value,
+        });
+        Some(
+            (0..1 << unknown.len())
+                .map(|combination: usize| {
+                    unknown
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| combination >> j & 1 == 1)
+                        .fold(known, |value, (_, &i)| value | 1 << i)
+                })
+                .collect(),
+        )
+    }
+
+    // `self >> shift` for every shift `amount` can take.
+    fn shift_right(&self, amount: &Value) -> Value {
+        let shifts = amount
+            .possible_values()
+            .unwrap_or_else(|| (0..=WIDTH).collect());
+        let bit = |i: usize, shift: usize| match i.checked_add(shift) {
+            Some(source) if source < WIDTH => self.0[source],
+            _ => Bit::Const(false),
+        };
+
+        Self(std::array::from_fn(|i| {
+            let first = bit(i, shifts[0]);
+            if shifts.iter().all(|&shift| bit(i, shift) == first) {
+                return first;
+            }
+            let mask = shifts
+                .iter()
+                .fold(amount.mask(), |mask, &shift| mask | bit(i, shift).mask());
+            Bit::Depends(mask)
+        }))
+    }
+}
+
+/// For every output of a program, the bits of the initial register A its
+/// value depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub width: usize,
+    pub outputs: Vec<u64>,
+}
+
+impl Analysis {
+    /// Whether every output depends on the same bits as the previous one,
+    /// three positions higher, and the outputs use up all bits of A.
+    pub fn consumes_three_bits_per_output(&self) -> bool {
+        let bits = u64::MAX
+            .checked_shr(64 - self.width.min(64) as u32)
+            .unwrap_or(0);
+        self.outputs.len() * 3 == self.width
+            && self
+                .outputs
+                .windows(2)
+                .all(|pair| pair[1] == (pair[0] << 3) & bits)
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, &mask) in self.outputs.iter().enumerate() {
+            let mut ranges = vec![];
+            let mut bit = 0;
+            while bit < 64 {
+                if mask >> bit & 1 == 0 {
+                    bit += 1;
+                    continue;
+                }
+                let end = bit + (mask >> bit).trailing_ones() as usize - 1;
+                ranges.push(match end == bit {
+                    true => format!("{bit}"),
+                    false => format!("{bit}-{end}"),
+                });
+                bit = end + 1;
+            }
+            match ranges.is_empty() {
+                true => writeln!(f, "Output {index}: constant")?,
+                false => writeln!(f, "Output {index}: A[{}]", ranges.join(", "))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the program with the lowest `width` bits of A unknown and B and C set
+/// to `registers`. Jumps whose condition depends on A are taken, so the
+/// analysis ends once all unknown bits have been shifted out of A.
+pub fn analyse(
+    program: &[usize],
+    width: usize,
+    registers: [usize; 2],
+    step_limit: usize,
+) -> Result<Analysis, MachineError> {
+    assert!(width <= 64, "Only the lowest 64 bits of A can be unknown");
+
+    let [b, c] = registers.map(Value::constant);
+    let a = Value(std::array::from_fn(|i| match i < width {
+        true => Bit::Depends(1 << i),
+        false => Bit::Const(false),
+    }));
+    let mut registers = [a, b, c];
+    let mut outputs = vec![];
+    let mut instruction_pointer = 0;
+    let mut steps = 0;
+
+    while instruction_pointer < program.len() {
+        if steps == step_limit {
+            return Err(MachineError::StepLimit { limit: step_limit });
+        }
+        steps += 1;
+
+        let instruction = Instruction::decode(program, instruction_pointer)?;
+        instruction_pointer += 2;
+
+        let combo = |combo: Combo| match combo {
+            Combo::Literal(value) => Value::constant(value),
+            Combo::Register(register) => registers[register as usize].clone(),
+        };
+        match instruction {
+            Instruction::Adv(operand) => registers[0] = registers[0].shift_right(&combo(operand)),
+            Instruction::Bxl(literal) => registers[1] = registers[1].xor(&Value::constant(literal)),
+            Instruction::Bst(operand) => registers[1] = combo(operand).modulo_8(),
+            Instruction::Jnz(literal) => {
+                if !registers[0].is_zero() {
+                    instruction_pointer = literal;
+                }
+            }
+            Instruction::Bxc => registers[1] = registers[1].xor(&registers[2]),
+            Instruction::Out(operand) => outputs.push(combo(operand).modulo_8().mask()),
+            Instruction::Bdv(operand) => registers[1] = registers[0].shift_right(&combo(operand)),
+            Instruction::Cdv(operand) => registers[2] = registers[0].shift_right(&combo(operand)),
+        }
+    }
+
+    Ok(Analysis { width, outputs })
+}
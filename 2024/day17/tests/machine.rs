@@ -0,0 +1,67 @@
+use day17_2024::machine::{disassemble, Machine, MachineError};
+
+const EXAMPLE: [usize; 6] = [0, 1, 5, 4, 3, 0];
+
+#[test]
+fn runs_the_puzzle_example() {
+    let mut machine = Machine::new([729, 0, 0], &EXAMPLE);
+    machine.run().unwrap();
+
+    assert_eq!(machine.output, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    assert!(machine.is_halted());
+}
+
+#[test]
+fn disassembles_every_instruction() {
+    assert_eq!(
+        disassemble(&EXAMPLE),
+        ["00: adv 1", "02: out A", "04: jnz 0"]
+    );
+    assert_eq!(
+        disassemble(&[1, 7, 2, 6, 4, 0, 6, 5, 7, 4]),
+        [
+            "00: bxl 7",
+            "02: bst C",
+            "04: bxc",
+            "06: bdv B",
+            "08: cdv A"
+        ]
+    );
+}
+
+#[test]
+fn disassembles_invalid_instructions_as_errors() {
+    assert_eq!(
+        disassemble(&[5, 7, 8, 0, 3]),
+        [
+            "00: <Invalid combo operand 7 at address 0>",
+            "02: <Invalid opcode 8 at address 2>",
+            "04: <Missing operand for the opcode at address 4>",
+        ]
+    );
+}
+
+#[test]
+fn stops_at_the_step_limit() {
+    // Jumps back to the start for as long as A isn't zero.
+    let program = [3, 0];
+    let mut machine = Machine::new([1, 0, 0], &program).step_limit(10);
+
+    assert_eq!(machine.run(), Err(MachineError::StepLimit { limit: 10 }));
+    assert_eq!(machine.steps(), 10);
+}
+
+#[test]
+fn rejects_invalid_combo_operands() {
+    let program = [0, 1, 2, 7, 3, 0];
+    let mut machine = Machine::new([5, 0, 0], &program);
+
+    assert_eq!(
+        machine.run(),
+        Err(MachineError::InvalidComboOperand {
+            address: 2,
+            operand: 7
+        })
+    );
+    assert_eq!(machine.registers[0], 2);
+}
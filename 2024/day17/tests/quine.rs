@@ -0,0 +1,14 @@
+use day17_2024::Day17;
+use utils_2024::{MaybeAnswer, Solution};
+
+#[test]
+fn finds_the_quine_of_the_puzzle_example() {
+    let computer = Day17::parse(include_str!("../example_part2.txt")).unwrap();
+    assert_eq!(Day17::part2(&computer), MaybeAnswer(Some(117440)));
+}
+
+#[test]
+fn reports_programs_without_a_quine() {
+    let computer = Day17::parse(include_str!("../example_part1.txt")).unwrap();
+    assert_eq!(Day17::part2(&computer), MaybeAnswer(None));
+}
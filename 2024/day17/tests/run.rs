@@ -0,0 +1,11 @@
+use day17_2024::Day17;
+use utils_2024::{MaybeAnswer, Solution};
+
+// Jumps back to the start for as long as A is non-zero, which is forever.
+const LOOPING: &str = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n";
+
+#[test]
+fn parses_programs_that_never_halt() {
+    let computer = Day17::parse(LOOPING).unwrap();
+    assert!(Day17::part1(&computer) == MaybeAnswer(None));
+}
@@ -0,0 +1,50 @@
+use day17_2024::symbolic::{analyse, Analysis};
+
+const STEP_LIMIT: usize = 1000;
+
+// bst A, bxl 1, cdv B, bxc, bxl 4, adv 3, out B, jnz 0
+const QUINE_LIKE: [usize; 16] = [2, 4, 1, 1, 7, 5, 4, 4, 1, 4, 0, 3, 5, 5, 3, 0];
+
+#[test]
+fn masks_the_bits_each_output_reads() {
+    // adv 3, out A, jnz 0
+    let analysis = analyse(&[0, 3, 5, 4, 3, 0], 9, [0, 0], STEP_LIMIT).unwrap();
+
+    assert_eq!(analysis.outputs, [0b111_000, 0b111_000_000, 0]);
+    assert!(analysis.consumes_three_bits_per_output());
+    assert_eq!(
+        analysis.to_string(),
+        "Output 0: A[3-5]\nOutput 1: A[6-8]\nOutput 2: constant\n"
+    );
+}
+
+#[test]
+fn recognises_programs_consuming_three_bits_per_output() {
+    let analysis = analyse(&QUINE_LIKE, 6, [0, 0], STEP_LIMIT).unwrap();
+
+    assert_eq!(analysis.outputs, [0b111_111, 0b111_000]);
+    assert!(analysis.consumes_three_bits_per_output());
+}
+
+#[test]
+fn rejects_programs_consuming_one_bit_per_output() {
+    // adv 1, out A, jnz 0
+    let analysis = analyse(&[0, 1, 5, 4, 3, 0], 3, [0, 0], STEP_LIMIT).unwrap();
+
+    assert_eq!(analysis.outputs, [0b110, 0b100, 0]);
+    assert!(!analysis.consumes_three_bits_per_output());
+}
+
+#[test]
+fn handles_programs_without_unknown_bits() {
+    let analysis = analyse(&QUINE_LIKE, 0, [0, 0], STEP_LIMIT).unwrap();
+
+    assert_eq!(
+        analysis,
+        Analysis {
+            width: 0,
+            outputs: vec![0]
+        }
+    );
+    assert!(!analysis.consumes_three_bits_per_output());
+}
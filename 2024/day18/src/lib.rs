@@ -0,0 +1,118 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+};
+use utils_2024::*;
+
+/// Falling bytes, of which part 1 drops the first `BYTES`, 12 in the example.
+pub struct Day18<const BYTES: usize = 1024>;
+
+#[derive(Clone, PartialEq)]
+pub enum MapField {
+    Space,
+    Corrupted,
+}
+
+fn search_path(map: &Grid<MapField>, start: Position) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let exit = (map.height() - 1, map.width() - 1);
+
+    queue.push_back((start, 0));
+    visited.insert(start);
+    while let Some((position, steps)) = queue.pop_front() {
+        if position == exit {
+            return Some(steps);
+        }
+
+        for neighbour in map.neighbours(position) {
+            if !visited.contains(&neighbour) && map[neighbour] == MapField::Space {
+                visited.insert(neighbour);
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+fn corrupt(map: &mut Grid<MapField>, tiles: &[[usize; 2]]) {
+    for tile in tiles {
+        map[(tile[1], tile[0])] = MapField::Corrupted;
+    }
+}
+
+fn steps_to_exit(map: &mut Grid<MapField>, tiles: &[[usize; 2]], bytes: usize) -> Option<usize> {
+    corrupt(map, &tiles[..bytes]);
+    search_path(map, (0, 0))
+}
+
+// Bisects the number of fallen bytes, keeping the exit reachable after `min`
+// of them and unreachable after `max`.
+fn first_blocking_tile(map: &Grid<MapField>, tiles: &[[usize; 2]]) -> Option<(usize, usize)> {
+    let blocked = |bytes: usize| {
+        let mut map = map.clone();
+        corrupt(&mut map, &tiles[..bytes]);
+        search_path(&map, (0, 0)).is_none()
+    };
+
+    if !blocked(tiles.len()) {
+        return None;
+    }
+
+    let mut min = 0;
+    let mut max = tiles.len();
+    while min + 1 < max {
+        let current = (min + max) / 2;
+        if blocked(current) {
+            max = current;
+        } else {
+            min = current;
+        }
+    }
+    Some((tiles[min][0], tiles[min][1]))
+}
+
+impl<const BYTES: usize> Solution for Day18<BYTES> {
+    type Input<'a> = (Grid<MapField>, Vec<[usize; 2]>);
+    type Part1 = MaybeAnswer<usize>;
+    type Part2 = MaybeAnswer<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let tiles = input
+            .get_lines()
+            .try_iter_numbers_in_line::<usize>(b',')
+            .map(|numbers| -> Result<_, Box<dyn Error>> {
+                <[usize; 2]>::try_from(numbers?).map_err(|numbers| {
+                    format!("Expected two coordinates, found {numbers:?}").into()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if tiles.len() < BYTES {
+            return Err(format!("Expected at least {BYTES} bytes, found {}", tiles.len()).into());
+        }
+
+        let (max_x, max_y) = tiles.iter().fold((0, 0), |(max_x, max_y), &[x, y]| {
+            (max_x.max(x), max_y.max(y))
+        });
+
+        let width = max_x + 1;
+        let height = max_y + 1;
+
+        Ok((Grid::new(width, height, MapField::Space), tiles))
+    }
+
+    fn part1((map, tiles): &Self::Input<'_>) -> MaybeAnswer<usize> {
+        steps_to_exit(&mut map.clone(), tiles, BYTES).into()
+    }
+
+    fn part2((map, tiles): &Self::Input<'_>) -> MaybeAnswer<String> {
+        first_blocking_tile(map, tiles)
+            .map(|(x, y)| format!("{},{}", x, y))
+            .into()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(MaybeAnswer<usize>, MaybeAnswer<String>), Box<dyn Error>> {
+    <Day18>::solve(input)
+}
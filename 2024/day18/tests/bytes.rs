@@ -0,0 +1,28 @@
+use day18_2024::Day18;
+use utils_2024::{MaybeAnswer, Solution};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn rejects_fewer_bytes_than_part1_drops() {
+    let err = <Day18>::parse(EXAMPLE).err().unwrap();
+    assert_eq!(err.to_string(), "Expected at least 1024 bytes, found 25");
+}
+
+#[test]
+fn reports_an_unreachable_exit() {
+    // The exit of the 3x3 area is cut off by the first two bytes already.
+    let input = Day18::<2>::parse("2,1\n1,2\n0,0\n").unwrap();
+    assert_eq!(Day18::<2>::part1(&input), MaybeAnswer(None));
+    assert_eq!(
+        Day18::<2>::part2(&input),
+        MaybeAnswer(Some("1,2".to_string()))
+    );
+}
+
+#[test]
+fn reports_bytes_that_never_block_the_exit() {
+    let input = Day18::<1>::parse("2,0\n0,2\n").unwrap();
+    assert_eq!(Day18::<1>::part1(&input), MaybeAnswer(Some(4)));
+    assert_eq!(Day18::<1>::part2(&input), MaybeAnswer(None));
+}
@@ -0,0 +1,66 @@
+use std::{collections::HashMap, error::Error};
+use utils_2024::*;
+
+pub struct Day19;
+
+fn find_matches<'a>(
+    design: &'a str,
+    patterns: &[&'a str],
+    cache: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    } else if let Some(value) = cache.get(&design) {
+        return *value;
+    }
+
+    let combinations = patterns
+        .iter()
+        .filter(|&pattern| design.starts_with(pattern))
+        .map(|pattern| find_matches(&design[pattern.len()..], patterns, cache))
+        .sum();
+
+    cache.insert(design, combinations);
+    combinations
+}
+
+fn calculate(designs: &[&str], patterns: &[&str]) -> (usize, usize) {
+    let mut cache = HashMap::new();
+
+    designs
+        .iter()
+        .map(|design| find_matches(design, patterns, &mut cache))
+        .filter(|result| *result != 0)
+        .fold((0, 0), |old, new| (old.0 + 1, old.1 + new))
+}
+
+impl Solution for Day19 {
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let [patterns, designs] = input
+            .sections()
+            .try_into()
+            .map_err(|_| "Expected towel patterns and a list of designs")?;
+
+        let [patterns] = patterns.exact_lines()?;
+        let patterns = patterns.text.split(", ").collect();
+        let designs = designs.lines().iter().map(|line| line.text).collect();
+
+        Ok((patterns, designs))
+    }
+
+    fn part1((patterns, designs): &Self::Input<'_>) -> usize {
+        calculate(designs, patterns).0
+    }
+
+    fn part2((patterns, designs): &Self::Input<'_>) -> usize {
+        calculate(designs, patterns).1
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day19::solve(input)
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::error::Error;
+use utils_2024::*;
+
+/// Race track, where cheats count if they save at least `SAVING` picoseconds.
+pub struct Day20<const SAVING: usize = 100>;
+
+#[derive(Debug, PartialEq)]
+pub enum MapField {
+    Space(Option<usize>),
+    Wall,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<MapField>,
+    start: Option<Position>,
+    exit: Option<Position>,
+}
+
+fn init_map(lines: &[Vec<char>]) -> Result<Map, Errors> {
+    let mut start = None;
+    let mut exit = None;
+    let mut grid = Vec::new();
+
+    for (y, line) in lines.iter().enumerate() {
+        let mut row = Vec::new();
+        for (x, &c) in line.iter().enumerate() {
+            row.push(match c {
+                'S' => {
+                    start = Some((y, x));
+                    MapField::Space(None)
+                }
+                'E' => {
+                    exit = Some((y, x));
+                    MapField::Space(Some(0))
+                }
+                '.' => MapField::Space(None),
+                '#' => MapField::Wall,
+                _ => {
+                    return Err(Errors::ParseError {
+                        line: y + 1,
+                        column: x + 1,
+                        token: c.to_string(),
+                        expected: "one of `#.SE`".to_string(),
+                    })
+                }
+            });
+        }
+        grid.push(row);
+    }
+
+    Ok(Map {
+        grid: Grid::from(grid),
+        start,
+        exit,
+    })
+}
+
+fn set_finish_distances(map: &mut Map) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    if let Some(exit) = map.exit {
+        queue.push_back((exit, 0));
+        visited.insert(exit);
+        while let Some((position, steps)) = queue.pop_front() {
+            map.grid[position] = MapField::Space(Some(steps));
+            if Some(position) == map.start {
+                return Some(steps);
+            }
+            for neighbour in map.grid.neighbours(position) {
+                if !visited.contains(&neighbour) && map.grid[neighbour] != MapField::Wall {
+                    visited.insert(neighbour);
+                    queue.push_back((neighbour, steps + 1));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn get_fields_to_check(
+    grid: &Grid<MapField>,
+    position: Position,
+    duration: usize,
+) -> HashSet<Position> {
+    let mut fields = HashSet::new();
+
+    for i in 0..=duration {
+        let j = (duration - i) as isize;
+        let i = i as isize;
+        let offsets = [
+            Point::new(i, j),
+            Point::new(i, -j),
+            Point::new(-j, i),
+            Point::new(-j, -i),
+        ];
+        for offset in offsets {
+            if let Some(field) = grid.offset(position, offset) {
+                fields.insert(field);
+            }
+        }
+    }
+
+    fields
+}
+
+fn find_cheat_possibilities(map: &Map, duration: usize, max: usize) -> usize {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut total = 0;
+
+    let start = map.start.unwrap();
+    queue.push_back((start, 0));
+    visited.insert(start);
+    while let Some((position, steps)) = queue.pop_front() {
+        for i in 2..=duration {
+            let fields = get_fields_to_check(&map.grid, position, i);
+            for field in fields {
+                if let MapField::Space(Some(cost)) = map.grid[field] {
+                    if steps + i + cost <= max {
+                        total += 1;
+                    }
+                }
+            }
+        }
+        for neighbour in map.grid.neighbours(position) {
+            if !visited.contains(&neighbour) && map.grid[neighbour] != MapField::Wall {
+                visited.insert(neighbour);
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+    total
+}
+
+impl<const SAVING: usize> Solution for Day20<SAVING> {
+    type Input<'a> = (Map, usize);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let lines = input.get_lines().try_lines_as_chars()?;
+
+        let mut map = init_map(&lines)?;
+        if map.start.is_none() {
+            return Err("Unable to find the start position".into());
+        }
+
+        let steps_without_cheat =
+            set_finish_distances(&mut map).ok_or("The end is unreachable without cheating")?;
+        if steps_without_cheat <= SAVING {
+            return Err(format!(
+                "The race takes {steps_without_cheat} picoseconds, so no cheat saves {SAVING}"
+            )
+            .into());
+        }
+
+        Ok((map, steps_without_cheat))
+    }
+
+    fn part1((map, steps_without_cheat): &Self::Input<'_>) -> usize {
+        find_cheat_possibilities(map, 2, steps_without_cheat.saturating_sub(SAVING))
+    }
+
+    fn part2((map, steps_without_cheat): &Self::Input<'_>) -> usize {
+        find_cheat_possibilities(map, 20, steps_without_cheat.saturating_sub(SAVING))
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    <Day20>::solve(input)
+}
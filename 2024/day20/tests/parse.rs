@@ -0,0 +1,19 @@
+use day20_2024::Day20;
+use utils_2024::{Errors, Solution};
+
+#[test]
+fn rejects_unknown_map_characters() {
+    let Err(err) = <Day20>::parse("#####\n#S.E#\n#.x.#\n#####\n") else {
+        panic!("Expected the map to be rejected");
+    };
+
+    assert_eq!(
+        *err.downcast::<Errors>().unwrap(),
+        Errors::ParseError {
+            line: 3,
+            column: 3,
+            token: "x".to_string(),
+            expected: "one of `#.SE`".to_string(),
+        }
+    );
+}
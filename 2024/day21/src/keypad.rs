@@ -1,5 +1,5 @@
 use std::collections::{HashMap, VecDeque};
-use utils_2024::UsizeOffset;
+use utils_2024::{Direction, Point, Position};
 
 const NUMERIC_KEYPAD: [[char; 3]; 4] = [
     ['7', '8', '9'],
@@ -74,7 +74,12 @@ pub fn find_shortest_paths(
 ) -> (String, usize, usize) {
     let mut queue = VecDeque::new();
     let mut visited = HashMap::new();
-    let directions = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+    let directions = [
+        Direction::West,
+        Direction::North,
+        Direction::South,
+        Direction::East,
+    ];
 
     queue.push_back((y, x, 0, String::new()));
     visited.insert((y, x), 0);
@@ -84,17 +89,12 @@ pub fn find_shortest_paths(
             return (format!("{}A", path), y, x);
         }
 
-        for &(cur_y, cur_x) in &directions {
-            if let (Ok(new_y), Ok(new_x)) = (y.offset(cur_y), x.offset(cur_x)) {
+        for direction in directions {
+            if let Ok((new_y, new_x)) = Position::try_from(Point::from((y, x)) + direction.delta())
+            {
                 if new_y < keypad.len() && new_x < keypad[new_y].len() {
                     let steps = steps + 1;
-                    let path = match (cur_y, cur_x) {
-                        (0, -1) => format!("{}<", path),
-                        (1, 0) => format!("{}v", path),
-                        (0, 1) => format!("{}>", path),
-                        (-1, 0) => format!("{}^", path),
-                        _ => panic!("Invalid direction"),
-                    };
+                    let path = format!("{}{}", path, direction.arrow());
 
                     if keypad[new_y][new_x] != '\0'
                         && count_direction_changes(&path) <= 1
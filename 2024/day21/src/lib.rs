@@ -0,0 +1,92 @@
+use std::error::Error;
+use utils_2024::*;
+mod keypad;
+mod robots;
+use crate::keypad::*;
+use crate::robots::*;
+
+pub struct Day21;
+
+fn create_robots(amount: usize) -> Vec<Robot> {
+    let mut robots = Vec::new();
+    robots.push(Robot::new(KeypadType::Numeric));
+    for _ in 0..amount {
+        robots.push(Robot::new(KeypadType::Directional));
+    }
+    robots
+}
+
+fn simulation(robots: &mut Vec<Robot>, index: usize, input: &str) -> usize {
+    let mut total = 0;
+    for c in input.chars() {
+        if let Some(value) = robots[index].search_cached_value(c) {
+            total += value;
+            continue;
+        }
+        let path = &robots[index].find_paths(c);
+        let result = match index != robots.len() - 1 {
+            true => simulation(robots, index + 1, &path.0),
+            false => path.0.len(),
+        };
+        total += result;
+        robots[index].insert_in_cache(c, result, path.1, path.2);
+    }
+    total
+}
+
+fn spawn_robots(input: &str, robots: usize) -> usize {
+    let value = input[0..=2].parse::<usize>().unwrap();
+
+    let mut robots = create_robots(robots);
+    let result = simulation(&mut robots, 0, input);
+    result * value
+}
+
+// Codes are three digits followed by `A`, like `029A`.
+fn check_code(number: usize, code: &str) -> Result<(), Errors> {
+    let chars: Vec<char> = code.chars().collect();
+
+    for column in 0..chars.len().max(4) {
+        let (valid, expected) = match (column, chars.get(column)) {
+            (0..=2, Some(c)) => (c.is_ascii_digit(), "digit"),
+            (0..=2, None) => (false, "digit"),
+            (3, c) => (c == Some(&'A'), "`A`"),
+            _ => (false, "end of line"),
+        };
+        if !valid {
+            return Err(Errors::ParseError {
+                line: number + 1,
+                column: column + 1,
+                token: chars[column.min(chars.len())..].iter().collect(),
+                expected: expected.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl Solution for Day21 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let codes = input.get_lines();
+        for (number, code) in codes.iter().enumerate() {
+            check_code(number, code)?;
+        }
+        Ok(codes)
+    }
+
+    fn part1(codes: &Self::Input<'_>) -> usize {
+        codes.iter().map(|line| spawn_robots(line, 2)).sum()
+    }
+
+    fn part2(codes: &Self::Input<'_>) -> usize {
+        codes.iter().map(|line| spawn_robots(line, 25)).sum()
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day21::solve(input)
+}
@@ -2,12 +2,14 @@ use std::collections::HashMap;
 
 use crate::keypad::*;
 
+type PathCache = HashMap<((usize, usize), char), (usize, usize, usize)>;
+
 #[derive(Clone, Debug)]
 pub struct Robot {
     pos_y: usize,
     pos_x: usize,
     keypad: Keypad,
-    cache: HashMap<((usize, usize), char), (usize, usize, usize)>,
+    cache: PathCache,
 }
 
 impl Robot {
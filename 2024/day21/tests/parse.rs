@@ -0,0 +1,49 @@
+use day21_2024::Day21;
+use utils_2024::{Errors, Solution};
+
+fn parse_error(input: &str) -> Errors {
+    let Err(err) = Day21::parse(input) else {
+        panic!("Expected the codes to be rejected");
+    };
+    *err.downcast::<Errors>().unwrap()
+}
+
+#[test]
+fn rejects_codes_not_made_of_three_digits_and_a() {
+    assert_eq!(
+        parse_error("029A\nABC\n"),
+        Errors::ParseError {
+            line: 2,
+            column: 1,
+            token: "ABC".to_string(),
+            expected: "digit".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error("029B\n"),
+        Errors::ParseError {
+            line: 1,
+            column: 4,
+            token: "B".to_string(),
+            expected: "`A`".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error("02\n"),
+        Errors::ParseError {
+            line: 1,
+            column: 3,
+            token: String::new(),
+            expected: "digit".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error("029A1\n"),
+        Errors::ParseError {
+            line: 1,
+            column: 5,
+            token: "1".to_string(),
+            expected: "end of line".to_string(),
+        }
+    );
+}
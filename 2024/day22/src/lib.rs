@@ -0,0 +1,92 @@
+use std::{collections::HashMap, error::Error};
+use utils_2024::*;
+
+pub struct Day22;
+
+fn mix(secret: usize, value: usize) -> usize {
+    secret ^ value
+}
+
+fn prune(secret: usize) -> usize {
+    secret % 16777216
+}
+
+fn calculation(number: usize, iterations: usize) -> (usize, Vec<isize>) {
+    let mut secret = number;
+    let mut numbers = Vec::new();
+    for _ in 0..iterations {
+        let value = secret * 64;
+        secret = mix(secret, value);
+        secret = prune(secret);
+        let value = secret / 32;
+        secret = mix(secret, value);
+        secret = prune(secret);
+        let value = secret * 2048;
+        secret = mix(secret, value);
+        secret = prune(secret);
+        numbers.push((secret % 10) as isize);
+    }
+    (secret, numbers)
+}
+
+fn bananas(numbers: &[isize]) -> HashMap<Vec<isize>, usize> {
+    let mut local_cache = HashMap::new();
+    for (i, number) in numbers.iter().enumerate().skip(4) {
+        let mut previous = numbers[i - 4..i].to_vec();
+        for i in 1..previous.len() {
+            previous[i - 1] = previous[i] - previous[i - 1];
+        }
+        previous[3] = *number - previous[3];
+        local_cache.entry(previous).or_insert(*number as usize);
+    }
+    local_cache
+}
+
+fn merge(
+    mut total: HashMap<Vec<isize>, usize>,
+    other: HashMap<Vec<isize>, usize>,
+) -> HashMap<Vec<isize>, usize> {
+    for (sequence, price) in other {
+        *total.entry(sequence).or_insert(0) += price;
+    }
+    total
+}
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input
+            .get_lines()
+            .iter()
+            .map(|line| line.parse::<usize>())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> usize {
+        Executor::new().map_reduce(
+            numbers,
+            || 0,
+            |&number| calculation(number, 2000).0,
+            |a, b| a + b,
+        )
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> usize {
+        let global_cache = Executor::new().map_reduce(
+            numbers,
+            HashMap::new,
+            |&number| bananas(&calculation(number, 2000).1),
+            merge,
+        );
+
+        // Without buyers there are no bananas to get.
+        global_cache.values().max().copied().unwrap_or(0)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, usize), Box<dyn Error>> {
+    Day22::solve(input)
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use utils_2024::Solution;
+pub mod connection;
+use crate::connection::*;
+
+pub struct Day23;
+
+fn triangles_with_t(connections: &[Connection]) -> usize {
+    let filtered = connections
+        .iter()
+        .filter(|conn| conn.from.starts_with("t") || conn.to.starts_with("t"));
+
+    let mut results = HashSet::new();
+    for filteredconn in filtered {
+        for conn2 in connections {
+            if !conn2.connected(filteredconn.from) || filteredconn == conn2 {
+                continue;
+            }
+            for conn3 in connections {
+                if !conn3.connected(filteredconn.to) {
+                    continue;
+                }
+                if let Some(result) = filteredconn.three_connected(conn2, conn3) {
+                    results.insert(result);
+                }
+            }
+        }
+    }
+    results.len()
+}
+
+type Neighbours<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+// Bron-Kerbosch: grows `party` by the `candidates` connected to all of its
+// members, skipping parties that could also take one of `excluded`.
+fn grow_party<'a>(
+    neighbours: &Neighbours<'a>,
+    party: &mut Vec<&'a str>,
+    mut candidates: HashSet<&'a str>,
+    mut excluded: HashSet<&'a str>,
+    largest: &mut Vec<&'a str>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if party.len() > largest.len() {
+            largest.clone_from(party);
+        }
+        return;
+    }
+
+    // Any maximal party contains the pivot or one of its non-neighbours.
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|computer| neighbours[*computer].len())
+        .copied()
+        .unwrap();
+    let remaining: Vec<&str> = candidates.difference(&neighbours[pivot]).copied().collect();
+
+    for computer in remaining {
+        let connected = &neighbours[computer];
+        party.push(computer);
+        grow_party(
+            neighbours,
+            party,
+            candidates.intersection(connected).copied().collect(),
+            excluded.intersection(connected).copied().collect(),
+            largest,
+        );
+        party.pop();
+        candidates.remove(computer);
+        excluded.insert(computer);
+    }
+}
+
+fn lan_party(connections: &[Connection]) -> String {
+    let mut neighbours = Neighbours::new();
+    for connection in connections {
+        neighbours
+            .entry(connection.from)
+            .or_default()
+            .insert(connection.to);
+        neighbours
+            .entry(connection.to)
+            .or_default()
+            .insert(connection.from);
+    }
+
+    let mut largest = Vec::new();
+    let candidates = neighbours.keys().copied().collect();
+    grow_party(
+        &neighbours,
+        &mut Vec::new(),
+        candidates,
+        HashSet::new(),
+        &mut largest,
+    );
+
+    largest.sort_unstable();
+    largest.join(",")
+}
+
+impl Solution for Day23 {
+    type Input<'a> = Vec<Connection<'a>>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        if let Some(line) = input.lines().find(|line| !line.contains('-')) {
+            return Err(format!("Invalid connection: {line}").into());
+        }
+        Ok(input.lines().map(|line| line.into()).collect())
+    }
+
+    fn part1(connections: &Self::Input<'_>) -> usize {
+        triangles_with_t(connections)
+    }
+
+    fn part2(connections: &Self::Input<'_>) -> String {
+        lan_party(connections)
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, String), Box<dyn Error>> {
+    Day23::solve(input)
+}
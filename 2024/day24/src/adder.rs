@@ -0,0 +1,201 @@
+use crate::netlist::{Gate, Netlist};
+use crate::Operation;
+use std::fmt::{self, Display, Formatter};
+
+/// The gates of a full-adder stage `i`, with `carry` coming from stage `i - 1`:
+///
+/// ```text
+/// partial = x_i XOR y_i        generate = x_i AND y_i
+/// z_i = partial XOR carry      propagate = partial AND carry
+/// next carry = generate OR propagate
+/// ```
+///
+/// Stage 0 is a half adder, and the carry of the last stage drives the
+/// highest `z` wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Partial,
+    Generate,
+    Sum,
+    Propagate,
+    Carry,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let role = match self {
+            Role::Partial => "partial sum XOR",
+            Role::Generate => "generate AND",
+            Role::Sum => "sum XOR",
+            Role::Propagate => "propagate AND",
+            Role::Carry => "carry OR",
+        };
+        write!(f, "{role}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Miswiring {
+    pub stage: usize,
+    pub role: Role,
+    pub expected: String,
+    /// The gate found in its place, if any.
+    pub found: Option<String>,
+}
+
+impl Display for Miswiring {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Stage {}, {}: expected {}, found {}",
+            self.stage,
+            self.role,
+            self.expected,
+            self.found.as_deref().unwrap_or("no such gate")
+        )
+    }
+}
+
+struct Checker<'a> {
+    netlist: &'a Netlist,
+    width: usize,
+    miswirings: Vec<Miswiring>,
+}
+
+impl Checker<'_> {
+    fn name(&self, wire: usize) -> &str {
+        self.netlist.name(wire)
+    }
+
+    fn describe(&self, gate: &Gate) -> String {
+        let [a, b] = gate.inputs.map(|wire| self.name(wire));
+        format!("{a} {} {b} -> {}", gate.operation, self.name(gate.output))
+    }
+
+    fn report(&mut self, stage: usize, role: Role, expected: String, found: Option<&Gate>) {
+        let found = found.map(|gate| self.describe(gate));
+        self.miswirings.push(Miswiring {
+            stage,
+            role,
+            expected,
+            found,
+        });
+    }
+
+    fn is_output(&self, wire: usize) -> bool {
+        self.name(wire).starts_with('z')
+    }
+
+    // The gate combining both wires, or else a gate reading one of them.
+    fn find(&self, operation: Operation, a: usize, b: usize) -> (Option<&Gate>, bool) {
+        let gates = self.netlist.gates().iter();
+        let mut readers = gates.filter(|gate| gate.operation == operation);
+        let exact = readers
+            .clone()
+            .find(|gate| gate.inputs.contains(&a) && gate.inputs.contains(&b));
+        match exact {
+            Some(gate) => (Some(gate), true),
+            None => (
+                readers.find(|gate| gate.inputs.contains(&a) || gate.inputs.contains(&b)),
+                false,
+            ),
+        }
+    }
+
+    // Checks a gate reading the inputs of `stage` and returns its output.
+    fn input_gate(
+        &mut self,
+        stage: usize,
+        role: Role,
+        operation: Operation,
+        target: Option<String>,
+    ) -> Option<usize> {
+        let inputs = [format!("x{stage:02}"), format!("y{stage:02}")];
+        match inputs.each_ref().map(|name| self.netlist.wire(name)) {
+            [Some(x), Some(y)] => self.inner_gate(stage, role, operation, [x, y], target),
+            _ => {
+                let [x, y] = inputs;
+                self.report(stage, role, format!("{x} {operation} {y}"), None);
+                None
+            }
+        }
+    }
+
+    // Checks a gate combining `a` and `b` and returns its output. Falls back to
+    // a gate reading only one of them, so the checker can continue past a
+    // swapped wire.
+    fn inner_gate(
+        &mut self,
+        stage: usize,
+        role: Role,
+        operation: Operation,
+        [a, b]: [usize; 2],
+        target: Option<String>,
+    ) -> Option<usize> {
+        let expected = format!("{} {operation} {}", self.name(a), self.name(b));
+        let expected = match &target {
+            Some(target) => format!("{expected} -> {target}"),
+            None => format!("{expected} -> an internal wire"),
+        };
+
+        let (gate, exact) = self.find(operation, a, b);
+        let gate = gate.cloned();
+        let wrong_output = gate.as_ref().is_some_and(|gate| match &target {
+            Some(target) => self.name(gate.output) != target,
+            None => self.is_output(gate.output),
+        });
+        if !exact || wrong_output {
+            self.report(stage, role, expected, gate.as_ref());
+        }
+        gate.map(|gate| gate.output)
+    }
+
+    fn check(&mut self) {
+        let width = self.width;
+        let last = |stage: usize| (stage + 1 == width).then(|| format!("z{:02}", stage + 1));
+
+        self.input_gate(0, Role::Partial, Operation::Xor, Some("z00".into()));
+        let mut carry = self.input_gate(0, Role::Generate, Operation::And, last(0));
+
+        for stage in 1..self.width {
+            let partial = self.input_gate(stage, Role::Partial, Operation::Xor, None);
+            let generate = self.input_gate(stage, Role::Generate, Operation::And, None);
+            let (Some(partial), Some(generate), Some(previous)) = (partial, generate, carry) else {
+                return;
+            };
+
+            let sum = Some(format!("z{stage:02}"));
+            self.inner_gate(stage, Role::Sum, Operation::Xor, [partial, previous], sum);
+            let propagate = self.inner_gate(
+                stage,
+                Role::Propagate,
+                Operation::And,
+                [partial, previous],
+                None,
+            );
+
+            carry = propagate.and_then(|propagate| {
+                self.inner_gate(
+                    stage,
+                    Role::Carry,
+                    Operation::Or,
+                    [generate, propagate],
+                    last(stage),
+                )
+            });
+        }
+    }
+}
+
+/// Checks that the netlist is a ripple-carry adder of two `width`-bit
+/// numbers and lists every gate that doesn't fit that pattern. Checking stops
+/// at the first stage whose carry can't be found.
+pub fn check(netlist: &Netlist, width: usize) -> Vec<Miswiring> {
+    let mut checker = Checker {
+        netlist,
+        width,
+        miswirings: Vec::new(),
+    };
+    checker.check();
+    checker.miswirings
+}
@@ -0,0 +1,115 @@
+use crate::{Connection, Operation, Wires};
+use std::fmt::Write;
+
+// Gates sorted by output, so exports don't depend on the hash map order.
+fn sorted_connections(wires: &Wires) -> Vec<&Connection> {
+    let mut connections: Vec<&Connection> = wires.connections.values().collect();
+    connections.sort_unstable_by(|a, b| a.output.cmp(&b.output));
+    connections
+}
+
+fn sorted_inputs(wires: &Wires) -> Vec<&str> {
+    let mut inputs: Vec<&str> = wires.values.keys().map(String::as_str).collect();
+    inputs.sort_unstable();
+    inputs
+}
+
+fn colour(operation: Operation) -> &'static str {
+    match operation {
+        Operation::And => "lightblue",
+        Operation::Or => "palegreen",
+        Operation::Xor => "gold",
+    }
+}
+
+/// Graphviz graph with a node for every wire and gate. Gates are coloured by
+/// their operation and the wires in `highlighted` are drawn in red.
+pub fn to_dot(wires: &Wires, highlighted: &[String]) -> String {
+    let mut dot = String::new();
+    let is_highlighted = |wire: &str| highlighted.iter().any(|name| name == wire);
+    let wire_style = |wire: &str| match is_highlighted(wire) {
+        true => ", color=red, fontcolor=red, penwidth=2",
+        false => "",
+    };
+
+    writeln!(dot, "digraph circuit {{").unwrap();
+    writeln!(dot, "    rankdir=LR;").unwrap();
+    for input in sorted_inputs(wires) {
+        let style = wire_style(input);
+        writeln!(dot, "    {input} [shape=invhouse{style}];").unwrap();
+    }
+
+    for connection in sorted_connections(wires) {
+        let output = &connection.output;
+        let shape = match connection.end_wire {
+            true => "house",
+            false => "ellipse",
+        };
+        let style = wire_style(output);
+        writeln!(dot, "    {output} [shape={shape}{style}];").unwrap();
+        writeln!(
+            dot,
+            "    gate_{output} [label=\"{}\", shape=box, style=filled, fillcolor={}];",
+            connection.operation,
+            colour(connection.operation)
+        )
+        .unwrap();
+        for input in [&connection.input1, &connection.input2] {
+            writeln!(dot, "    {input} -> gate_{output};").unwrap();
+        }
+        let edge_style = match is_highlighted(output) {
+            true => " [color=red, penwidth=2]",
+            false => "",
+        };
+        writeln!(dot, "    gate_{output} -> {output}{edge_style};").unwrap();
+    }
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+/// Structural Verilog module `name` with one gate primitive per connection,
+/// the initial wires as inputs and the `z` wires as outputs.
+pub fn to_verilog(wires: &Wires, name: &str) -> String {
+    let connections = sorted_connections(wires);
+    let inputs = sorted_inputs(wires);
+    let (outputs, internal): (Vec<&Connection>, Vec<&Connection>) = connections
+        .iter()
+        .partition(|connection| connection.end_wire);
+
+    let ports: Vec<String> = inputs
+        .iter()
+        .map(|input| format!("input {input}"))
+        .chain(outputs.iter().map(|gate| format!("output {}", gate.output)))
+        .collect();
+
+    let mut verilog = String::new();
+    writeln!(verilog, "module {name} (").unwrap();
+    writeln!(verilog, "    {}", ports.join(",\n    ")).unwrap();
+    writeln!(verilog, ");").unwrap();
+
+    if !internal.is_empty() {
+        writeln!(verilog).unwrap();
+        for gate in &internal {
+            writeln!(verilog, "    wire {};", gate.output).unwrap();
+        }
+    }
+
+    writeln!(verilog).unwrap();
+    for connection in connections {
+        let primitive = match connection.operation {
+            Operation::And => "and",
+            Operation::Or => "or",
+            Operation::Xor => "xor",
+        };
+        writeln!(
+            verilog,
+            "    {primitive} g_{output} ({output}, {}, {});",
+            connection.input1,
+            connection.input2,
+            output = connection.output
+        )
+        .unwrap();
+    }
+    writeln!(verilog, "endmodule").unwrap();
+    verilog
+}
@@ -0,0 +1,371 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt::{self, Display, Formatter},
+    mem,
+    ops::Deref,
+    sync::OnceLock,
+};
+
+use utils_2024::*;
+pub mod adder;
+pub mod export;
+pub mod netlist;
+pub mod search;
+pub mod verify;
+use crate::adder::Miswiring;
+use crate::netlist::*;
+use crate::verify::Verification;
+
+pub struct Day24;
+
+// Number of swapped pairs of gate outputs in the puzzle input.
+const SWAPS: usize = 4;
+
+// Seed of the random additions, shared by the search and the final check so
+// that both simulate the same cases.
+const SEED: u64 = 24;
+
+/// Names of wires, displayed comma-separated like the part 2 answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WireNames(pub Vec<String>);
+
+impl Deref for WireNames {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for WireNames {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.join(","))
+    }
+}
+
+/// Gates by output wire, the initial wire values and the netlist for both.
+pub struct Wires {
+    connections: HashMap<String, Connection>,
+    values: HashMap<String, u8>,
+    netlist: Netlist,
+    // Filled in by the first call to `swap_pairs`.
+    swap_pairs: OnceLock<Option<Vec<(String, String)>>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let operation = match self {
+            Operation::And => "AND",
+            Operation::Or => "OR",
+            Operation::Xor => "XOR",
+        };
+        write!(f, "{operation}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Connection {
+    input1: String,
+    operation: Operation,
+    input2: String,
+    output: String,
+    start_wire: bool,
+    end_wire: bool,
+}
+
+impl Operation {
+    fn parse(field: &Field) -> Result<Self, Errors> {
+        match field.text {
+            "AND" => Ok(Operation::And),
+            "OR" => Ok(Operation::Or),
+            "XOR" => Ok(Operation::Xor),
+            token => Err(Errors::ParseError {
+                line: field.line,
+                column: field.column,
+                token: token.to_string(),
+                expected: "AND, OR or XOR".to_string(),
+            }),
+        }
+    }
+}
+
+impl Connection {
+    fn new(input1: String, operation: Operation, input2: String, output: String) -> Connection {
+        let start_wire = input1.starts_with("x") || input1.starts_with("y");
+        let end_wire = output.starts_with("z");
+
+        Connection {
+            input1,
+            operation,
+            input2,
+            output,
+            start_wire,
+            end_wire,
+        }
+    }
+}
+
+fn find_broken_xor_wires(
+    connection_map: &HashMap<String, Connection>,
+    last_output: &str,
+) -> Vec<String> {
+    connection_map
+        .values()
+        .filter_map(|connection| {
+            if connection.operation == Operation::Xor {
+                if !connection.start_wire && !connection.end_wire {
+                    Some(connection.output.clone())
+                } else {
+                    None
+                }
+            } else if connection.end_wire && connection.output != last_output {
+                Some(connection.output.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Bit of an `x` or `y` input wire.
+fn input_bit(wire: &str) -> Option<usize> {
+    wire.strip_prefix(['x', 'y'])?.parse().ok()
+}
+
+fn swap_two_elements(
+    connection_map: &mut HashMap<String, Connection>,
+    output1: &String,
+    output2: &String,
+) {
+    let mut item1 = connection_map.remove(output1).unwrap();
+    let mut item2 = connection_map.remove(output2).unwrap();
+
+    mem::swap(&mut item1.output, &mut item2.output);
+    connection_map.insert(output1.clone(), item2);
+    connection_map.insert(output2.clone(), item1);
+}
+
+// Swaps found by following the circuit back from the last output, or `None`
+// if the circuit is too broken for that.
+fn fix_broken_wires(
+    connection_map: &mut HashMap<String, Connection>,
+    width: usize,
+) -> Option<Vec<(String, String)>> {
+    let last_output = format!("z{width:02}");
+    let broken_wires = find_broken_xor_wires(connection_map, &last_output);
+    let mut z_node = last_output;
+
+    let mut queue = VecDeque::new();
+    queue.push_back((z_node.clone(), 0, 0));
+
+    let mut swappairs = Vec::new();
+    let mut swapitem: Option<String> = None;
+    // A swap can add a cycle, which the walk would follow forever.
+    let mut remaining = connection_map.len().pow(2);
+    while let Some((wire, and_count, or_count)) = queue.pop_front() {
+        remaining = remaining.checked_sub(1)?;
+        let mut result = connection_map.get(&wire)?.clone();
+
+        if result.operation == Operation::Xor && result.start_wire {
+            let nbr = input_bit(&result.input1)?.checked_sub(1)?;
+            z_node = format!("z{nbr:02}");
+        }
+
+        if broken_wires.contains(&result.output) && broken_wires.contains(&z_node) {
+            swappairs.push((result.output.to_string(), z_node.clone()));
+            swap_two_elements(connection_map, &result.output, &z_node);
+            result = connection_map.get(&wire)?.clone();
+        }
+
+        let (and_count, or_count) = match result.operation {
+            Operation::And => (and_count + 1, 0),
+            _ => (0, or_count + 1),
+        };
+
+        if (and_count > 1 || or_count > 1) && input_bit(&result.input1) != Some(0) {
+            if let Some(item) = swapitem {
+                swappairs.push((item.clone(), result.output.clone()));
+                swap_two_elements(connection_map, &result.output, &item);
+                swapitem = None;
+            } else {
+                swapitem = Some(result.output);
+            }
+        }
+
+        if !result.start_wire {
+            queue.push_back((result.input1.clone(), and_count, or_count));
+            queue.push_back((result.input2.clone(), and_count, or_count));
+        }
+    }
+    Some(swappairs)
+}
+
+fn netlist(
+    connections: &HashMap<String, Connection>,
+    values: &HashMap<String, u8>,
+) -> Result<Netlist, NetlistError> {
+    Netlist::new(connections.values(), values.keys().map(String::as_str))
+}
+
+impl Solution for Day24 {
+    type Input<'a> = Wires;
+    type Part1 = u64;
+    type Part2 = MaybeAnswer<WireNames>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let [values, gates] = input
+            .sections()
+            .try_into()
+            .map_err(|_| "Expected initial wire values and a list of gates")?;
+
+        let value = Template::new("{}: {}");
+        let mut value_map = HashMap::new();
+        for &line in values.lines() {
+            let [wire, bit] = value.captures(line)?;
+            let value = match bit.text {
+                "0" => 0,
+                "1" => 1,
+                _ => {
+                    return Err(Errors::ParseError {
+                        line: bit.line,
+                        column: bit.column,
+                        token: bit.text.to_string(),
+                        expected: "0 or 1".to_string(),
+                    }
+                    .into())
+                }
+            };
+            value_map.insert(wire.text.to_string(), value);
+        }
+
+        let gate = Template::new("{} {} {} -> {}");
+        let mut connections = HashMap::new();
+        for &line in gates.lines() {
+            let [input1, operation, input2, output] = gate.captures(line)?;
+            let item = Connection::new(
+                input1.text.to_string(),
+                Operation::parse(&operation)?,
+                input2.text.to_string(),
+                output.text.to_string(),
+            );
+            if connections.insert(output.text.to_string(), item).is_some() {
+                return Err(NetlistError::MultipleDrivers(output.text.to_string()).into());
+            }
+        }
+
+        let netlist = netlist(&connections, &value_map)?;
+        Ok(Wires {
+            connections,
+            values: value_map,
+            netlist,
+            swap_pairs: OnceLock::new(),
+        })
+    }
+
+    fn part1(wires: &Self::Input<'_>) -> u64 {
+        wires
+            .netlist
+            .evaluate(&wires.values)
+            .expect("Parsing checked that every wire has a value")
+            .number('z')
+    }
+
+    fn part2(wires: &Self::Input<'_>) -> MaybeAnswer<WireNames> {
+        wires.swapped_wires().map(WireNames).into()
+    }
+}
+
+impl Wires {
+    /// Number of bits of the `x` and `y` inputs.
+    pub fn width(&self) -> usize {
+        self.values
+            .keys()
+            .filter_map(|wire| input_bit(wire))
+            .max()
+            .map_or(0, |bit| bit + 1)
+    }
+
+    pub fn netlist(&self) -> &Netlist {
+        &self.netlist
+    }
+
+    pub fn check_adder(&self) -> Vec<Miswiring> {
+        adder::check(&self.netlist, self.width())
+    }
+
+    /// Pairs of gate outputs to swap to repair the adder. Uses the swaps found
+    /// by the structural heuristic if simulation confirms them, and searches
+    /// for them otherwise. Only the first call does the work.
+    pub fn swap_pairs(&self) -> Option<&[(String, String)]> {
+        self.swap_pairs
+            .get_or_init(|| self.find_swap_pairs())
+            .as_deref()
+    }
+
+    fn find_swap_pairs(&self) -> Option<Vec<(String, String)>> {
+        let mut connections = self.connections.clone();
+        let pairs = fix_broken_wires(&mut connections, self.width()).unwrap_or_default();
+
+        let is_adder = || {
+            netlist(&connections, &self.values)
+                .and_then(|netlist| verify::verify(&netlist, self.width(), 1000, SEED))
+                .is_ok_and(|verification| verification.passed())
+        };
+        if pairs.len() == SWAPS && is_adder() {
+            return Some(pairs);
+        }
+        search::find_swaps(&self.connections, &self.values, self.width(), SWAPS)
+    }
+
+    /// Sorted outputs that part 2 swaps to repair the adder.
+    pub fn swapped_wires(&self) -> Option<Vec<String>> {
+        let mut wires: Vec<String> = self
+            .swap_pairs()?
+            .iter()
+            .flat_map(|(first, second)| [first.clone(), second.clone()])
+            .collect();
+        wires.sort_unstable();
+        Some(wires)
+    }
+
+    /// The circuit after swapping the outputs found by part 2, or the original
+    /// one if there are none.
+    pub fn repaired(&self) -> Result<Wires, NetlistError> {
+        let mut connections = self.connections.clone();
+
+        for (first, second) in self.swap_pairs().unwrap_or_default() {
+            swap_two_elements(&mut connections, first, second);
+        }
+        Ok(Wires {
+            netlist: netlist(&connections, &self.values)?,
+            connections,
+            values: self.values.clone(),
+            swap_pairs: OnceLock::new(),
+        })
+    }
+}
+
+/// Checks the repaired circuit against edge cases and `random` random additions.
+pub fn verify_repaired(wires: &Wires, random: usize) -> Result<Verification, NetlistError> {
+    let repaired = wires.repaired()?;
+    verify::verify(&repaired.netlist, repaired.width(), random, SEED)
+}
+
+/// Sum of the `x` and `y` inputs, and the `z` output of the repaired circuit.
+pub fn repaired_sum(wires: &Wires) -> Result<(u64, u64), NetlistError> {
+    let repaired = wires.repaired()?;
+    let values = repaired.netlist.evaluate(&repaired.values)?;
+    Ok((values.number('x') + values.number('y'), values.number('z')))
+}
+
+pub fn solve(input: &str) -> Result<(u64, MaybeAnswer<WireNames>), Box<dyn Error>> {
+    Day24::solve(input)
+}
@@ -0,0 +1,235 @@
+use crate::{Connection, Operation};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetlistError {
+    /// Wires of a loop of gates, each one feeding into the next.
+    Cycle(Vec<String>),
+    Undriven(String),
+    MultipleDrivers(String),
+    /// Number of input bits of an adder whose sums don't fit into 64 bits.
+    UnsupportedWidth(usize),
+}
+
+impl Display for NetlistError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            NetlistError::Cycle(wires) => write!(f, "Gates form a cycle: {}", wires.join(" -> ")),
+            NetlistError::Undriven(wire) => write!(f, "Wire {wire} has no value and no gate"),
+            NetlistError::MultipleDrivers(wire) => {
+                write!(f, "Wire {wire} is the output of more than one gate")
+            }
+            NetlistError::UnsupportedWidth(width) => {
+                write!(
+                    f,
+                    "Unable to check an adder of {width} bits, only 1 to 63 fit"
+                )
+            }
+        }
+    }
+}
+
+impl Error for NetlistError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gate {
+    pub inputs: [usize; 2],
+    pub operation: Operation,
+    pub output: usize,
+}
+
+/// Gates referring to wires by index, sorted so that every gate comes after
+/// the gates driving its inputs.
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    gates: Vec<Gate>,
+}
+
+impl Netlist {
+    /// Builds the netlist for `connections`, where `inputs` are the wires
+    /// that get their values from outside the circuit.
+    pub fn new<'a>(
+        connections: impl IntoIterator<Item = &'a Connection>,
+        inputs: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, NetlistError> {
+        let mut netlist = Netlist {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            gates: Vec::new(),
+        };
+
+        let inputs: HashSet<usize> = inputs.into_iter().map(|name| netlist.id(name)).collect();
+        let mut driver = HashMap::new();
+        for connection in connections {
+            let gate = Gate {
+                inputs: [
+                    netlist.id(&connection.input1),
+                    netlist.id(&connection.input2),
+                ],
+                operation: connection.operation,
+                output: netlist.id(&connection.output),
+            };
+            if inputs.contains(&gate.output) || driver.insert(gate.output, gate.clone()).is_some() {
+                return Err(NetlistError::MultipleDrivers(connection.output.clone()));
+            }
+        }
+
+        for wire in 0..netlist.names.len() {
+            if !inputs.contains(&wire) && !driver.contains_key(&wire) {
+                return Err(NetlistError::Undriven(netlist.names[wire].clone()));
+            }
+        }
+
+        netlist.gates = topological_order(&netlist.names, &driver)?;
+        Ok(netlist)
+    }
+
+    fn id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Evaluates every gate once, in topological order.
+    pub fn evaluate(&self, inputs: &HashMap<String, u8>) -> Result<Values<'_>, NetlistError> {
+        let mut values = vec![None; self.names.len()];
+        for (name, &value) in inputs {
+            if let Some(&wire) = self.ids.get(name) {
+                values[wire] = Some(value);
+            }
+        }
+
+        for gate in &self.gates {
+            let [a, b] = gate.inputs.map(|wire| {
+                values[wire].ok_or_else(|| NetlistError::Undriven(self.names[wire].clone()))
+            });
+            let (a, b) = (a?, b?);
+            values[gate.output] = Some(match gate.operation {
+                Operation::And => a & b,
+                Operation::Or => a | b,
+                Operation::Xor => a ^ b,
+            });
+        }
+
+        Ok(Values {
+            netlist: self,
+            values,
+        })
+    }
+}
+
+// Kahn's algorithm. Gates left over afterwards are part of or behind a cycle.
+fn topological_order(
+    names: &[String],
+    driver: &HashMap<usize, Gate>,
+) -> Result<Vec<Gate>, NetlistError> {
+    let mut pending: HashMap<usize, usize> = HashMap::new();
+    let mut readers: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (&output, gate) in driver {
+        let gate_inputs = gate.inputs.iter().filter(|wire| driver.contains_key(wire));
+        pending.insert(output, gate_inputs.clone().count());
+        for &wire in gate_inputs {
+            readers.entry(wire).or_default().push(output);
+        }
+    }
+
+    let mut ready: Vec<usize> = pending
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&output, _)| output)
+        .collect();
+    // Keep the order independent of the hash map's iteration order.
+    ready.sort_unstable();
+    let mut ready = VecDeque::from(ready);
+
+    let mut order = Vec::with_capacity(driver.len());
+    while let Some(output) = ready.pop_front() {
+        order.push(driver[&output].clone());
+        for &reader in readers.get(&output).into_iter().flatten() {
+            let count = pending.get_mut(&reader).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.push_back(reader);
+            }
+        }
+    }
+
+    if order.len() == driver.len() {
+        return Ok(order);
+    }
+
+    // Follow unresolved inputs backwards until a wire repeats.
+    let mut wire = *pending
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .map(|(wire, _)| wire)
+        .min()
+        .unwrap();
+    let mut path = Vec::new();
+    while !path.contains(&wire) {
+        path.push(wire);
+        wire = *driver[&wire]
+            .inputs
+            .iter()
+            .find(|input| pending.get(input).is_some_and(|&count| count > 0))
+            .unwrap();
+    }
+    let start = path.iter().position(|&visited| visited == wire).unwrap();
+    let mut cycle: Vec<String> = path[start..]
+        .iter()
+        .rev()
+        .map(|&wire| names[wire].clone())
+        .collect();
+    cycle.push(cycle[0].clone());
+    Err(NetlistError::Cycle(cycle))
+}
+
+/// Values of all wires after evaluating a netlist.
+pub struct Values<'a> {
+    netlist: &'a Netlist,
+    values: Vec<Option<u8>>,
+}
+
+impl Values<'_> {
+    pub fn get(&self, name: &str) -> Option<u8> {
+        self.values[self.netlist.wire(name)?]
+    }
+
+    /// Combines the wires `<prefix>00`, `<prefix>01`, ... into a number, with
+    /// `<prefix>00` as the least significant bit.
+    pub fn number(&self, prefix: char) -> u64 {
+        self.netlist
+            .names
+            .iter()
+            .zip(&self.values)
+            .filter_map(|(name, value)| {
+                let bit = name.strip_prefix(prefix)?.parse::<u32>().ok()?;
+                if bit >= u64::BITS {
+                    return None;
+                }
+                Some((bit, (*value)?))
+            })
+            .fold(0, |number, (bit, value)| number | (value as u64) << bit)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::netlist::Netlist;
+use crate::{input_bit, netlist, swap_two_elements, verify, Connection, SEED};
+use std::collections::HashMap;
+
+// Random additions per candidate on top of the edge cases. Solutions get
+// verified with many more before they are returned.
+const SAMPLES: usize = 64;
+const FINAL_SAMPLES: usize = 10000;
+
+struct Search<'a> {
+    values: &'a HashMap<String, u8>,
+    width: usize,
+}
+
+impl Search<'_> {
+    // Lowest wrong bit of the sum, `Some(None)` if the circuit adds correctly
+    // and `None` if the swaps made it invalid, for example by adding a cycle.
+    fn first_failing_bit(
+        &self,
+        connections: &HashMap<String, Connection>,
+        samples: usize,
+    ) -> Option<Option<usize>> {
+        let netlist = netlist(connections, self.values).ok()?;
+        let verification = verify::verify(&netlist, self.width, samples, SEED).ok()?;
+        Some(verification.failing_bits.first().copied())
+    }
+
+    // Outputs of the gates whose highest input bit is close to `bit`, as
+    // every stage of the adder only uses the inputs up to its own bit.
+    fn candidates(&self, connections: &HashMap<String, Connection>, bit: usize) -> Vec<String> {
+        let Ok(netlist) = netlist(connections, self.values) else {
+            return Vec::new();
+        };
+        let highest = highest_input_bits(&netlist);
+
+        let mut candidates: Vec<String> = netlist
+            .gates()
+            .iter()
+            .filter(|gate| {
+                highest
+                    .get(&gate.output)
+                    .is_some_and(|&highest| highest + 1 >= bit && highest <= bit + 1)
+            })
+            .map(|gate| netlist.name(gate.output).to_string())
+            .collect();
+        candidates.sort_unstable();
+        candidates
+    }
+
+    fn search(
+        &self,
+        connections: &mut HashMap<String, Connection>,
+        swaps: usize,
+        first_failing_bit: Option<usize>,
+        pairs: &mut Vec<(String, String)>,
+    ) -> bool {
+        let Some(bit) = first_failing_bit else {
+            return swaps == 0 && self.first_failing_bit(connections, FINAL_SAMPLES) == Some(None);
+        };
+        if swaps == 0 {
+            return false;
+        }
+
+        let candidates = self.candidates(connections, bit);
+        for (i, first) in candidates.iter().enumerate() {
+            for second in &candidates[i + 1..] {
+                swap_two_elements(connections, first, second);
+
+                // Only follow swaps that fix the lowest wrong bit.
+                if let Some(next) = self.first_failing_bit(connections, SAMPLES) {
+                    if next.is_none_or(|next| next > bit) {
+                        pairs.push((first.clone(), second.clone()));
+                        if self.search(connections, swaps - 1, next, pairs) {
+                            return true;
+                        }
+                        pairs.pop();
+                    }
+                }
+
+                swap_two_elements(connections, first, second);
+            }
+        }
+        false
+    }
+}
+
+fn highest_input_bits(netlist: &Netlist) -> HashMap<usize, usize> {
+    let mut highest = HashMap::new();
+    for gate in netlist.gates() {
+        let bit = gate
+            .inputs
+            .iter()
+            .filter_map(|&wire| {
+                highest
+                    .get(&wire)
+                    .copied()
+                    .or_else(|| input_bit(netlist.name(wire)))
+            })
+            .max();
+        if let Some(bit) = bit {
+            highest.insert(gate.output, bit);
+        }
+    }
+    highest
+}
+
+/// Looks for exactly `swaps` pairs of gate outputs that turn the circuit into
+/// an adder of two `width`-bit numbers. Every step swaps two gates near the
+/// lowest wrong bit of the sum and keeps the swap if it moves that bit up.
+pub fn find_swaps(
+    connections: &HashMap<String, Connection>,
+    values: &HashMap<String, u8>,
+    width: usize,
+    swaps: usize,
+) -> Option<Vec<(String, String)>> {
+    if !(1..64).contains(&width) {
+        return None;
+    }
+
+    let search = Search { values, width };
+    let mut connections = connections.clone();
+    let first_failing_bit = search.first_failing_bit(&connections, SAMPLES)?;
+
+    let mut pairs = Vec::new();
+    search
+        .search(&mut connections, swaps, first_failing_bit, &mut pairs)
+        .then_some(pairs)
+}
@@ -0,0 +1,97 @@
+use crate::netlist::{Netlist, NetlistError};
+use std::collections::{BTreeSet, HashMap};
+
+// xorshift64*, enough to spread test inputs over all bits.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub cases: usize,
+    /// Inputs for which the circuit got the wrong sum.
+    pub failures: Vec<(u64, u64)>,
+    /// Bits of the sum that were wrong in any failure, in ascending order.
+    pub failing_bits: Vec<usize>,
+}
+
+impl Verification {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+// All ones, carries rippling through the whole adder and from every bit, and
+// every bit on its own.
+fn edge_cases(width: usize) -> Vec<(u64, u64)> {
+    let mask = u64::MAX >> (64 - width);
+    let mut cases = vec![
+        (0, 0),
+        (mask, 0),
+        (0, mask),
+        (mask, 1),
+        (1, mask),
+        (mask, mask),
+    ];
+
+    for bit in 0..width {
+        let single = 1 << bit;
+        let above = mask & !(single - 1);
+        cases.extend([
+            (single, 0),
+            (0, single),
+            (single, single),
+            (above, single),
+            (single, above),
+        ]);
+    }
+    cases
+}
+
+/// Runs the edge cases and `random` random additions of two `width`-bit
+/// numbers through `netlist` and compares the `z` wires to `x + y`. The sums
+/// need to fit into 64 bits, so `width` has to be below 64.
+pub fn verify(
+    netlist: &Netlist,
+    width: usize,
+    random: usize,
+    seed: u64,
+) -> Result<Verification, NetlistError> {
+    if !(1..64).contains(&width) {
+        return Err(NetlistError::UnsupportedWidth(width));
+    }
+
+    let mask = u64::MAX >> (64 - width);
+    let mut rng = Random(seed | 1);
+    let mut cases = edge_cases(width);
+    cases.extend((0..random).map(|_| (rng.next() & mask, rng.next() & mask)));
+
+    let mut failures = Vec::new();
+    let mut failing_bits = BTreeSet::new();
+    let mut inputs = HashMap::new();
+    for &(x, y) in &cases {
+        for bit in 0..width {
+            inputs.insert(format!("x{bit:02}"), (x >> bit & 1) as u8);
+            inputs.insert(format!("y{bit:02}"), (y >> bit & 1) as u8);
+        }
+
+        let difference = netlist.evaluate(&inputs)?.number('z') ^ (x + y);
+        if difference != 0 {
+            failures.push((x, y));
+            failing_bits.extend((0..64).filter(|bit| difference >> bit & 1 == 1));
+        }
+    }
+
+    Ok(Verification {
+        cases: cases.len(),
+        failures,
+        failing_bits: failing_bits.into_iter().collect(),
+    })
+}
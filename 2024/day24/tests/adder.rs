@@ -0,0 +1,38 @@
+use day24_2024::adder::{Miswiring, Role};
+use day24_2024::Day24;
+use utils_2024::{MaybeAnswer, Solution};
+
+mod common;
+
+use common::{wires, ADDER};
+
+#[test]
+fn accepts_a_ripple_carry_adder() {
+    let wires = wires(ADDER);
+
+    assert_eq!(wires.width(), 2);
+    assert_eq!(wires.check_adder(), []);
+    assert_eq!(Day24::part1(&wires), 0b100);
+    // A working adder has no 4 swapped pairs to find.
+    assert_eq!(Day24::part2(&wires), MaybeAnswer(None));
+}
+
+#[test]
+fn flags_swapped_outputs() {
+    let miswired = ADDER
+        .replace("c00 -> z01", "c00 -> tmp")
+        .replace("c00 -> r01", "c00 -> z01")
+        .replace("c00 -> tmp", "c00 -> r01");
+    let miswirings = wires(&miswired).check_adder();
+
+    assert_eq!(
+        miswirings.first(),
+        Some(&Miswiring {
+            stage: 1,
+            role: Role::Sum,
+            expected: "p01 XOR c00 -> z01".to_string(),
+            found: Some("p01 XOR c00 -> r01".to_string()),
+        })
+    );
+    assert!(miswirings.iter().all(|miswiring| miswiring.stage == 1));
+}
@@ -0,0 +1,19 @@
+use day24_2024::{Day24, Wires};
+use utils_2024::Solution;
+
+pub const VALUES: &str = "x00: 1\nx01: 1\ny00: 1\ny01: 0\n\n";
+
+// Two-bit ripple-carry adder.
+pub const ADDER: &str = "\
+x00 XOR y00 -> z00
+x00 AND y00 -> c00
+x01 XOR y01 -> p01
+x01 AND y01 -> g01
+p01 XOR c00 -> z01
+p01 AND c00 -> r01
+g01 OR r01 -> z02
+";
+
+pub fn wires(gates: &str) -> Wires {
+    Day24::parse(&format!("{VALUES}{gates}")).unwrap()
+}
@@ -0,0 +1,54 @@
+use day24_2024::{export, Day24, Wires};
+use utils_2024::Solution;
+
+// Half adder.
+fn half_adder() -> Wires {
+    Day24::parse("x00: 1\ny00: 1\n\nx00 XOR y00 -> z00\ny00 AND x00 -> z01\n").unwrap()
+}
+
+#[test]
+fn exports_dot() {
+    let dot = export::to_dot(&half_adder(), &["z01".to_string()]);
+
+    assert_eq!(
+        dot,
+        "\
+digraph circuit {
+    rankdir=LR;
+    x00 [shape=invhouse];
+    y00 [shape=invhouse];
+    z00 [shape=house];
+    gate_z00 [label=\"XOR\", shape=box, style=filled, fillcolor=gold];
+    x00 -> gate_z00;
+    y00 -> gate_z00;
+    gate_z00 -> z00;
+    z01 [shape=house, color=red, fontcolor=red, penwidth=2];
+    gate_z01 [label=\"AND\", shape=box, style=filled, fillcolor=lightblue];
+    y00 -> gate_z01;
+    x00 -> gate_z01;
+    gate_z01 -> z01 [color=red, penwidth=2];
+}
+"
+    );
+}
+
+#[test]
+fn exports_verilog() {
+    let verilog = export::to_verilog(&half_adder(), "half_adder");
+
+    assert_eq!(
+        verilog,
+        "\
+module half_adder (
+    input x00,
+    input y00,
+    output z00,
+    output z01
+);
+
+    xor g_z00 (z00, x00, y00);
+    and g_z01 (z01, y00, x00);
+endmodule
+"
+    );
+}
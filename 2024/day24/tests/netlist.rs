@@ -0,0 +1,46 @@
+use day24_2024::{netlist::NetlistError, Day24};
+use utils_2024::Solution;
+
+const VALUES: &str = "x00: 1\ny00: 0\n\n";
+
+fn netlist_error(gates: &str) -> NetlistError {
+    let Err(err) = Day24::parse(&format!("{VALUES}{gates}")) else {
+        panic!("Expected the circuit to be rejected");
+    };
+    *err.downcast::<NetlistError>()
+        .expect("Expected a netlist error")
+}
+
+#[test]
+fn rejects_gates_forming_a_cycle() {
+    let error = netlist_error("x00 AND aaa -> bbb\nbbb OR y00 -> aaa\naaa XOR bbb -> z00\n");
+
+    let NetlistError::Cycle(mut wires) = error else {
+        panic!("Expected a cycle, got {error}");
+    };
+    assert_eq!(wires.first(), wires.last());
+    wires.pop();
+    wires.sort_unstable();
+    assert_eq!(wires, ["aaa", "bbb"]);
+}
+
+#[test]
+fn rejects_wires_without_value_or_gate() {
+    let error = netlist_error("x00 AND qqq -> z00\n");
+
+    assert_eq!(error, NetlistError::Undriven("qqq".to_string()));
+}
+
+#[test]
+fn rejects_wires_driven_twice() {
+    let error = netlist_error("x00 AND y00 -> z00\nx00 XOR y00 -> z00\n");
+
+    assert_eq!(error, NetlistError::MultipleDrivers("z00".to_string()));
+}
+
+#[test]
+fn rejects_gates_driving_an_input() {
+    let error = netlist_error("x00 AND y00 -> z00\nx00 XOR z00 -> y00\n");
+
+    assert_eq!(error, NetlistError::MultipleDrivers("y00".to_string()));
+}
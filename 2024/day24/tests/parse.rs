@@ -0,0 +1,36 @@
+use day24_2024::Day24;
+use utils_2024::{Errors, Solution};
+
+#[test]
+fn rejects_unknown_operations() {
+    let Err(err) = Day24::parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n") else {
+        panic!("Expected the gate to be rejected");
+    };
+
+    assert_eq!(
+        *err.downcast::<Errors>().unwrap(),
+        Errors::ParseError {
+            line: 4,
+            column: 5,
+            token: "NAND".to_string(),
+            expected: "AND, OR or XOR".to_string(),
+        }
+    );
+}
+
+#[test]
+fn rejects_wire_values_other_than_bits() {
+    let Err(err) = Day24::parse("x00: 1\ny00: 7\n\nx00 AND y00 -> z00\n") else {
+        panic!("Expected the value to be rejected");
+    };
+
+    assert_eq!(
+        *err.downcast::<Errors>().unwrap(),
+        Errors::ParseError {
+            line: 2,
+            column: 6,
+            token: "7".to_string(),
+            expected: "0 or 1".to_string(),
+        }
+    );
+}
@@ -0,0 +1,62 @@
+use day24_2024::{netlist::NetlistError, verify::verify, verify_repaired, Day24};
+use utils_2024::Solution;
+
+mod common;
+
+use common::{wires, ADDER};
+
+#[test]
+fn passes_a_working_adder() {
+    let verification = verify(wires(ADDER).netlist(), 2, 100, 1).unwrap();
+
+    assert!(verification.passed());
+    assert_eq!(verification.cases, 116);
+    assert_eq!(verification.failing_bits, []);
+}
+
+#[test]
+fn fails_a_broken_adder() {
+    // The sum and propagate gates of stage 1 drive each other's outputs.
+    let broken = ADDER
+        .replace("c00 -> z01", "c00 -> tmp")
+        .replace("c00 -> r01", "c00 -> z01")
+        .replace("c00 -> tmp", "c00 -> r01");
+    let verification = verify(wires(&broken).netlist(), 2, 100, 1).unwrap();
+
+    assert!(!verification.passed());
+    assert!(verification.failures.contains(&(0b10, 0b00)));
+    assert_eq!(verification.failing_bits, [1, 2]);
+}
+
+#[test]
+fn rejects_adders_without_room_for_the_sum() {
+    let wires = wires(ADDER);
+
+    for width in [0, 64] {
+        assert_eq!(
+            verify(wires.netlist(), width, 100, 1),
+            Err(NetlistError::UnsupportedWidth(width))
+        );
+    }
+}
+
+#[test]
+fn reports_circuits_too_wide_to_verify() {
+    let mut input = String::new();
+    for wire in ['x', 'y'] {
+        for bit in 0..64 {
+            input += &format!("{wire}{bit:02}: 0\n");
+        }
+    }
+    input += "\n";
+    for bit in 0..64 {
+        input += &format!("x{bit:02} XOR y{bit:02} -> z{bit:02}\n");
+    }
+    let wires = Day24::parse(&input).unwrap();
+
+    assert_eq!(wires.width(), 64);
+    assert_eq!(
+        verify_repaired(&wires, 100),
+        Err(NetlistError::UnsupportedWidth(64))
+    );
+}
@@ -0,0 +1,69 @@
+use std::error::Error;
+use utils_2024::*;
+
+pub struct Day25;
+
+fn calculate_heights(lock: &Grid<char>) -> (bool, Vec<i32>) {
+    let mut heights = vec![-1; lock.width()];
+    let is_lock = lock
+        .rows()
+        .next()
+        .is_some_and(|row| row.iter().all(|&field| field == '#'));
+
+    for row in lock.rows() {
+        for (i, field) in row.iter().enumerate() {
+            if *field == '#' {
+                heights[i] += 1;
+            }
+        }
+    }
+    (is_lock, heights)
+}
+
+impl Solution for Day25 {
+    type Input<'a> = (Vec<Vec<i32>>, Vec<Vec<i32>>);
+    type Part1 = usize;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+        for section in input.sections() {
+            let (is_lock, heights) = calculate_heights(&section.grid()?);
+            if is_lock {
+                locks.push(heights);
+            } else {
+                keys.push(heights);
+            }
+        }
+
+        Ok((locks, keys))
+    }
+
+    fn part1((locks, keys): &Self::Input<'_>) -> usize {
+        let mut total = 0;
+        for lock in locks {
+            for key in keys {
+                let mut possible = true;
+                for (field_lock, field_key) in lock.iter().zip(key.iter()) {
+                    if *field_lock + *field_key > 5 {
+                        possible = false;
+                    }
+                }
+                if possible {
+                    total += 1;
+                }
+            }
+        }
+
+        total
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+pub fn solve(input: &str) -> Result<(usize, NoAnswer), Box<dyn Error>> {
+    Day25::solve(input)
+}
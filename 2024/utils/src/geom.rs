@@ -0,0 +1,166 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Errors, Position};
+
+/// Signed `(y, x)` coordinate, used for offsets and for positions that may
+/// leave the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub y: isize,
+    pub x: isize,
+}
+
+impl Point {
+    pub const fn new(y: isize, x: isize) -> Self {
+        Self { y, x }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.y.abs_diff(other.y) + self.x.abs_diff(other.x)
+    }
+}
+
+impl From<Position> for Point {
+    fn from((y, x): Position) -> Self {
+        Self::new(y as isize, x as isize)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.delta()
+    }
+}
+
+impl TryFrom<Point> for Position {
+    type Error = Errors;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        let y = usize::try_from(point.y).map_err(|_| Errors::OutOfBoundsError)?;
+        let x = usize::try_from(point.x).map_err(|_| Errors::OutOfBoundsError)?;
+
+        Ok((y, x))
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.y + other.y, self.x + other.x)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.y - other.y, self.x - other.x)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.y, -self.x)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.y * factor, self.x * factor)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point::new(-1, 0),
+            Direction::East => Point::new(0, 1),
+            Direction::South => Point::new(1, 0),
+            Direction::West => Point::new(0, -1),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// Parses the `^`, `>`, `v` and `<` arrows used by the puzzles.
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::{Index, IndexMut};
+
+use crate::geom::{Direction, Point};
+
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_8: [Point; 8] = [
+    Point::new(-1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+    Point::new(1, 0),
+    Point::new(1, -1),
+    Point::new(0, -1),
+    Point::new(-1, -1),
+];
+
+/// Rectangular grid stored row by row, addressed with `(y, x)` positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (y, x): Position) -> bool {
+        y < self.height && x < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Moves `position` by `delta`, returning `None` once it leaves the grid.
+    pub fn offset(&self, position: Position, delta: impl Into<Point>) -> Option<Position> {
+        let next = Position::try_from(Point::from(position) + delta.into()).ok()?;
+
+        self.contains(next).then_some(next)
+    }
+
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction))
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (y, x)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn position<F>(&self, predicate: F) -> Option<Position>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U, F>(&self, function: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(function).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, (y, x): Position) -> Option<usize> {
+        self.contains((y, x)).then(|| y * self.width + x)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("Position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .expect("Position outside of the grid")
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid need the same length"
+        );
+
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+}
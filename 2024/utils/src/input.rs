@@ -0,0 +1,194 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs::read_to_string,
+    io::{self, Read},
+    path::{self, Path, PathBuf},
+};
+
+/// Environment variable overriding the directory that holds the `<year>/dayNN` folders.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub struct InputError {
+    path: PathBuf,
+    source: io::Error,
+    // Whether the path was looked up by input name rather than given.
+    named: bool,
+}
+
+// `main` reports returned errors through `Debug`, so keep it as readable as `Display`.
+impl fmt::Debug for InputError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Unable to read {}: {}", self.path.display(), self.source)?;
+        if self.named && self.source.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                " (set {INPUT_DIR_VAR} or pass a path to use a different input)"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl InputError {
+    /// Kind of the underlying I/O error, like `NotFound` for a missing input.
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads `path`, or standard input when the path is `-`.
+pub fn read_path(path: &Path) -> Result<String, InputError> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        return io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|source| InputError {
+                path: PathBuf::from("<stdin>"),
+                source,
+                named: false,
+            });
+    }
+
+    read_to_string(path).map_err(|source| InputError {
+        path: path.to_path_buf(),
+        source,
+        named: false,
+    })
+}
+
+/// Locates puzzle inputs like `input.txt` or `example_part2.txt` in `<dir>/<year>/dayNN/`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses `$AOC_INPUT_DIR`, falling back to the root of this workspace.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Self::new(dir),
+            None => Self::new(workspace_root()),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}"))
+            .join(format!("{name}.txt"))
+    }
+
+    pub fn read(&self, year: u16, day: u8, name: &str) -> Result<String, InputError> {
+        read_path(&self.path(year, day, name)).map_err(|err| InputError { named: true, ..err })
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    manifest
+        .parent()
+        .and_then(Path::parent)
+        .unwrap_or(manifest)
+        .to_path_buf()
+}
+
+/// Input for the binary of a single day: the first argument is either `-` for
+/// standard input, a path, or the name of an alternate input like `example`.
+/// Arguments with a path separator or ending in `.txt` are always paths.
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    load_arg(year, day, env::args().nth(1).as_deref())
+}
+
+/// Like [`load`], for binaries that take further arguments.
+pub fn load_arg(year: u16, day: u8, arg: Option<&str>) -> Result<String, InputError> {
+    match arg {
+        Some(arg) if is_path(arg) => read_path(Path::new(arg)),
+        Some(name) => Inputs::from_env().read(year, day, name),
+        None => Inputs::from_env().read(year, day, "input"),
+    }
+}
+
+fn is_path(arg: &str) -> bool {
+    arg == "-"
+        || arg.contains(path::is_separator)
+        || arg.ends_with(".txt")
+        || Path::new(arg).is_file()
+}
+
+/// Arguments of a day binary that takes flags. Arguments starting with `--`
+/// are flags, and the only other one selects the input as for [`load`].
+pub struct Args {
+    flags: Vec<String>,
+    pub input: String,
+}
+
+impl Args {
+    /// Checks the arguments against `known` flags, where flags taking a value
+    /// end in `=`, like `--blinks=`.
+    pub fn load(year: u16, day: u8, known: &[&str]) -> Result<Self, Box<dyn Error>> {
+        Self::from_args(year, day, known, env::args().skip(1))
+    }
+
+    /// Like [`Args::load`], for `args` other than the ones of this process.
+    pub fn from_args(
+        year: u16,
+        day: u8,
+        known: &[&str],
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (flags, args): (Vec<String>, Vec<String>) =
+            args.into_iter().partition(|arg| arg.starts_with("--"));
+
+        for flag in &flags {
+            let name = match flag.split_once('=') {
+                Some((name, _)) => &flag[..=name.len()],
+                None => flag.as_str(),
+            };
+            if !known.contains(&name) {
+                return Err(
+                    format!("Unknown flag {flag}, expected one of {}", known.join(", ")).into(),
+                );
+            }
+        }
+        if let [_, unexpected, ..] = &args[..] {
+            return Err(
+                format!("Unexpected argument {unexpected}, only the input can be given").into(),
+            );
+        }
+
+        let input = load_arg(year, day, args.first().map(String::as_str))?;
+        Ok(Self { flags, input })
+    }
+
+    /// Whether `flag`, like `--render`, was passed.
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|arg| arg == flag)
+    }
+
+    /// Value of `flag` if it was passed as `--flag=value`.
+    pub fn value(&self, flag: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .find_map(|arg| arg.strip_prefix(flag)?.strip_prefix('='))
+    }
+}
@@ -1,23 +1,158 @@
 use std::{
-    marker::Send,
-    marker::Sync,
+    any::type_name,
+    error::Error,
+    fmt::{self, Display, Formatter},
     str::FromStr,
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        mpsc, Arc,
-    },
-    thread,
 };
 
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub use geom::{Direction, Point};
+pub use grid::{Grid, Position};
+pub use parallel::{Control, Executor};
+pub use parse::{Field, Line, Section, Template};
+
+pub trait Solution {
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+
+    fn solve(input: &str) -> Result<(Self::Part1, Self::Part2), Box<dyn Error>> {
+        let parsed = Self::parse(input)?;
+        Ok((Self::part1(&parsed), Self::part2(&parsed)))
+    }
+}
+
+/// Answer for parts without a puzzle, like the second half of day 25.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Answer for parts that can come up empty on some inputs. A missing value
+/// is displayed like [`NoAnswer`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for MaybeAnswer<T> {
+    fn from(value: Option<T>) -> Self {
+        MaybeAnswer(value)
+    }
+}
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => NoAnswer.fmt(f),
+        }
+    }
+}
+
 pub trait StringVecHandling {
     fn lines_as_chars(&self) -> Vec<Vec<char>>;
     fn digits_grid(&self) -> Vec<Vec<u8>>;
-    fn numbers_in_line(&self, delimiter: u8) -> Vec<Vec<i128>>;
-    fn numbers_in_column(&self, delimiter: u8) -> Vec<Vec<i128>>;
+    fn numbers_in_line<T: FromStr>(&self, delimiter: u8) -> Vec<Vec<T>>;
+    /// Lazy form of `numbers_in_line`, parsing one line per item.
+    fn iter_numbers_in_line<T: FromStr>(&self, delimiter: u8) -> impl Iterator<Item = Vec<T>>;
+    fn numbers_in_column<T: FromStr>(&self, delimiter: u8) -> Vec<Vec<T>>;
     fn split_with_key<K, V>(&self, delimiter: char) -> Vec<(K, Vec<V>)>
     where
         K: FromStr,
         V: FromStr;
+
+    /// Like `lines_as_chars`, but rejects rows that differ in length from the first one.
+    fn try_lines_as_chars(&self) -> Result<Vec<Vec<char>>, Errors>;
+    /// Like `digits_grid`, with the same check as `try_lines_as_chars`.
+    fn try_digits_grid(&self) -> Result<Vec<Vec<u8>>, Errors>;
+    fn try_numbers_in_line<T: FromStr>(&self, delimiter: u8) -> Result<Vec<Vec<T>>, Errors>;
+    /// Lazy form of `try_numbers_in_line`, with an error for every line that
+    /// has a token of the wrong type.
+    fn try_iter_numbers_in_line<T: FromStr>(
+        &self,
+        delimiter: u8,
+    ) -> impl Iterator<Item = Result<Vec<T>, Errors>>;
+    fn try_numbers_in_column<T: FromStr>(&self, delimiter: u8) -> Result<Vec<Vec<T>>, Errors>;
+    /// Like `split_with_key`, but every non-empty line needs the delimiter.
+    fn try_split_with_key<K, V>(&self, delimiter: char) -> Result<Vec<(K, Vec<V>)>, Errors>
+    where
+        K: FromStr,
+        V: FromStr;
+}
+
+// Yields the trimmed, non-empty tokens of `line` along with their 1-based column.
+fn tokens(line: &str, delimiter: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(delimiter)
+        .scan(0, move |offset, item| {
+            let start = *offset + item.len() - item.trim_start().len();
+            *offset += item.len() + delimiter.len_utf8();
+            Some((start, item.trim()))
+        })
+        .filter(|(_, token)| !token.is_empty())
+        .map(move |(start, token)| (line[..start].chars().count() + 1, token))
+}
+
+fn parse_token<T: FromStr>(line: usize, column: usize, token: &str) -> Result<T, Errors> {
+    token.parse().map_err(|_| Errors::ParseError {
+        line,
+        column,
+        token: token.to_string(),
+        expected: type_name::<T>().to_string(),
+    })
+}
+
+fn numbers<T: FromStr>(line: &str, delimiter: u8) -> Vec<T> {
+    line.split(delimiter as char)
+        .filter_map(|item| item.trim().parse().ok())
+        .collect()
+}
+
+// Adds `row` to `rows` if it has the same length as the rows before it.
+fn push_row<T>(
+    rows: &mut Vec<Vec<T>>,
+    row: Vec<T>,
+    number: usize,
+    line: &str,
+) -> Result<(), Errors> {
+    let width = rows.first().map_or(row.len(), Vec::len);
+    if row.len() != width {
+        return Err(Errors::ParseError {
+            line: number,
+            column: width.min(row.len()) + 1,
+            token: line.to_string(),
+            expected: format!("row of {width} characters"),
+        });
+    }
+    rows.push(row);
+    Ok(())
+}
+
+// Splits numbered lines into rows of characters that all need the same length.
+fn char_rows<'a>(lines: impl Iterator<Item = (usize, &'a str)>) -> Result<Vec<Vec<char>>, Errors> {
+    let mut rows = Vec::new();
+
+    for (number, line) in lines {
+        push_row(&mut rows, line.chars().collect(), number, line)?;
+    }
+
+    Ok(rows)
+}
+
+fn try_numbers<T: FromStr>(line: usize, content: &str, delimiter: u8) -> Result<Vec<T>, Errors> {
+    tokens(content, delimiter as char)
+        .map(|(column, token)| parse_token(line, column, token))
+        .collect()
 }
 
 impl StringVecHandling for Vec<String> {
@@ -35,25 +170,21 @@ impl StringVecHandling for Vec<String> {
             .collect()
     }
 
-    fn numbers_in_line(&self, delimiter: u8) -> Vec<Vec<i128>> {
+    fn numbers_in_line<T: FromStr>(&self, delimiter: u8) -> Vec<Vec<T>> {
+        self.iter_numbers_in_line(delimiter).collect()
+    }
+
+    fn iter_numbers_in_line<T: FromStr>(&self, delimiter: u8) -> impl Iterator<Item = Vec<T>> {
         self.iter()
-            .map(|line| {
-                line.split(delimiter as char)
-                    .filter_map(|item| item.trim().parse::<i128>().ok())
-                    .collect::<Vec<i128>>()
-            })
-            .filter(|vec| !vec.is_empty())
-            .collect()
+            .map(move |line| numbers(line, delimiter))
+            .filter(|vec: &Vec<T>| !vec.is_empty())
     }
 
-    fn numbers_in_column(&self, delimiter: u8) -> Vec<Vec<i128>> {
-        let mut columns: Vec<Vec<i128>> = Vec::new();
+    fn numbers_in_column<T: FromStr>(&self, delimiter: u8) -> Vec<Vec<T>> {
+        let mut columns: Vec<Vec<T>> = Vec::new();
 
         for line in self {
-            let numbers: Vec<i128> = line
-                .split(delimiter as char)
-                .filter_map(|item| item.trim().parse::<i128>().ok())
-                .collect();
+            let numbers: Vec<T> = numbers(line, delimiter);
 
             if columns.len() < numbers.len() {
                 columns.resize_with(numbers.len(), Vec::new);
@@ -97,14 +228,115 @@ impl StringVecHandling for Vec<String> {
 
         items
     }
+
+    fn try_lines_as_chars(&self) -> Result<Vec<Vec<char>>, Errors> {
+        char_rows(
+            self.iter()
+                .enumerate()
+                .map(|(number, line)| (number + 1, line.as_str())),
+        )
+    }
+
+    fn try_digits_grid(&self) -> Result<Vec<Vec<u8>>, Errors> {
+        let mut grid = Vec::new();
+
+        for (number, line) in self.iter().enumerate() {
+            let mut row = Vec::new();
+            for (column, c) in line.chars().enumerate() {
+                if c.is_whitespace() {
+                    continue;
+                }
+                let digit = c.to_digit(10).ok_or_else(|| Errors::ParseError {
+                    line: number + 1,
+                    column: column + 1,
+                    token: c.to_string(),
+                    expected: "digit".to_string(),
+                })?;
+                row.push(digit as u8);
+            }
+            push_row(&mut grid, row, number + 1, line)?;
+        }
+
+        Ok(grid)
+    }
+
+    fn try_numbers_in_line<T: FromStr>(&self, delimiter: u8) -> Result<Vec<Vec<T>>, Errors> {
+        self.try_iter_numbers_in_line(delimiter).collect()
+    }
+
+    fn try_iter_numbers_in_line<T: FromStr>(
+        &self,
+        delimiter: u8,
+    ) -> impl Iterator<Item = Result<Vec<T>, Errors>> {
+        self.iter()
+            .enumerate()
+            .map(move |(number, line)| try_numbers(number + 1, line, delimiter))
+            .filter(|numbers: &Result<Vec<T>, Errors>| {
+                numbers.as_ref().map_or(true, |numbers| !numbers.is_empty())
+            })
+    }
+
+    fn try_numbers_in_column<T: FromStr>(&self, delimiter: u8) -> Result<Vec<Vec<T>>, Errors> {
+        let mut columns: Vec<Vec<T>> = Vec::new();
+
+        for (number, line) in self.iter().enumerate() {
+            let numbers = try_numbers(number + 1, line, delimiter)?;
+
+            if columns.len() < numbers.len() {
+                columns.resize_with(numbers.len(), Vec::new);
+            }
+
+            for (i, num) in numbers.into_iter().enumerate() {
+                columns[i].push(num);
+            }
+        }
+
+        Ok(columns)
+    }
+
+    fn try_split_with_key<K, V>(&self, delimiter: char) -> Result<Vec<(K, Vec<V>)>, Errors>
+    where
+        K: FromStr,
+        V: FromStr,
+    {
+        let mut items = Vec::new();
+
+        for (number, line) in self.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some((key, values)) = line.split_once(delimiter) else {
+                return Err(Errors::ParseError {
+                    line: number + 1,
+                    column: line.chars().count() + 1,
+                    token: line.clone(),
+                    expected: format!("`{delimiter}`"),
+                });
+            };
+
+            let offset = key.chars().count() + 1;
+            let (column, token) = tokens(key, delimiter).next().unwrap_or((1, key));
+            let key = parse_token(number + 1, column, token)?;
+
+            let values = tokens(values, ' ')
+                .map(|(column, token)| parse_token(number + 1, offset + column, token))
+                .collect::<Result<Vec<V>, Errors>>()?;
+
+            items.push((key, values));
+        }
+
+        Ok(items)
+    }
 }
 
 pub trait StringHandling {
     fn get_lines(&self) -> Vec<String>;
     fn get_chars_trimmed(&self) -> Vec<char>;
+    fn sections(&self) -> Vec<Section<'_>>;
 }
 
-impl StringHandling for String {
+impl StringHandling for str {
     fn get_lines(&self) -> Vec<String> {
         self.lines().map(String::from).collect()
     }
@@ -112,84 +344,74 @@ impl StringHandling for String {
     fn get_chars_trimmed(&self) -> Vec<char> {
         self.trim().chars().collect()
     }
+
+    fn sections(&self) -> Vec<Section<'_>> {
+        parse::sections(self)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Errors {
     OutOfBoundsError,
     IntegerOverflow,
+    ParseError {
+        line: usize,
+        column: usize,
+        token: String,
+        expected: String,
+    },
 }
 
-pub trait UsizeOffset {
-    fn offset(&self, value: isize) -> Result<Self, Errors>
-    where
-        Self: Sized;
-}
-
-impl UsizeOffset for usize {
-    fn offset(&self, value: isize) -> Result<Self, Errors>
-    where
-        Self: Sized,
-    {
-        if value < 0 {
-            let abs_offset = value.unsigned_abs();
-            if abs_offset > *self {
-                return Err(Errors::OutOfBoundsError);
-            }
-            Ok(*self - abs_offset)
-        } else {
-            self.checked_add(value as usize)
-                .ok_or(Errors::IntegerOverflow)
+impl Display for Errors {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Errors::OutOfBoundsError => write!(f, "Position is out of bounds"),
+            Errors::IntegerOverflow => write!(f, "Integer overflow"),
+            Errors::ParseError {
+                line,
+                column,
+                token,
+                expected,
+            } => write!(
+                f,
+                "Line {line}, column {column}: expected {expected}, found `{token}`"
+            ),
         }
     }
 }
 
+impl Error for Errors {}
+
 pub trait Bruteforce {
     fn skip(&mut self, steps: usize);
 }
 
+/// Evaluates `function` on `parameter` skipped by 0, 1, 2, ... steps and
+/// returns the first value any of the threads comes across. Every thread
+/// keeps its own copy of `parameter` and skips it by the number of threads.
 pub fn bruteforce<F, T>(function: F, parameter: T, threads: usize) -> Option<usize>
 where
-    F: Send + 'static + Fn(&T) -> Option<usize> + Sync,
-    T: Bruteforce + Clone + Send + 'static,
+    F: Fn(&T) -> Option<usize> + Sync,
+    T: Bruteforce + Clone + Sync,
 {
-    let found = Arc::new(AtomicBool::new(false));
-    let (tx, rx) = mpsc::channel();
-
-    let function = Arc::new(function);
-    let mut handles = vec![];
-
-    for thread_id in 0..threads {
-        let found = Arc::clone(&found);
-        let function = Arc::clone(&function);
-        let tx = tx.clone();
-        let mut parameter = parameter.clone();
-
-        let handle = thread::spawn(move || {
-            parameter.skip(thread_id);
-            loop {
-                if found.load(Ordering::SeqCst) {
-                    return;
-                }
-
-                if let Some(value) = function(&parameter) {
-                    if !found.swap(true, Ordering::SeqCst) {
-                        let _ = tx.send(value);
-                    }
-                }
-                parameter.skip(threads);
-            }
-        });
-
-        handles.push(handle);
-    }
-
-    drop(tx);
-
-    let result = rx.recv().ok();
+    Executor::new()
+        .threads(threads)
+        .find_map_any_strided(0..usize::MAX, parameter, T::skip, function)
+        .map(|(_, value)| value)
+}
 
-    for handle in handles {
-        let _ = handle.join();
-    }
-    result
+/// Like [`bruteforce`], but always returns the smallest number of steps for
+/// which `function` returns a value, together with that value. Threads still
+/// give up on step counts above the best one found so far.
+pub fn bruteforce_min<F, T>(function: F, parameter: T, threads: usize) -> Option<(usize, usize)>
+where
+    F: Fn(&T) -> Option<usize> + Sync,
+    T: Bruteforce + Clone + Sync,
+{
+    Executor::new().threads(threads).find_map_min_strided(
+        0..usize::MAX,
+        parameter,
+        T::skip,
+        function,
+    )
 }
@@ -0,0 +1,347 @@
+use std::{
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// Handle shared with a running search to cancel it and to see how far it got.
+#[derive(Clone, Debug, Default)]
+pub struct Control {
+    cancelled: Arc<AtomicBool>,
+    completed: Arc<AtomicUsize>,
+}
+
+impl Control {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Number of candidates or items processed so far.
+    pub fn completed(&self) -> usize {
+        self.completed.load(Ordering::Relaxed)
+    }
+}
+
+type Progress<'a> = Box<dyn Fn(usize, usize) + Sync + 'a>;
+
+/// Runs work on a pool of scoped threads. Work is split into chunks which idle
+/// threads claim in ascending order, so no thread waits on a slow neighbour.
+pub struct Executor<'a> {
+    threads: usize,
+    chunk_size: Option<usize>,
+    control: Control,
+    progress: Option<Progress<'a>>,
+}
+
+impl Default for Executor<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Executor<'a> {
+    pub fn new() -> Self {
+        Self {
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+            chunk_size: None,
+            control: Control::new(),
+            progress: None,
+        }
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Defaults to splitting the work into about 16 chunks per thread, with at
+    /// most 1024 candidates each.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    pub fn control(mut self, control: Control) -> Self {
+        self.control = control;
+        self
+    }
+
+    /// Called with `(completed, total)` after every finished chunk.
+    pub fn on_progress(mut self, progress: impl Fn(usize, usize) + Sync + 'a) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    // Hands out chunks of `range` to `threads` workers until `work` returns
+    // false or the search gets cancelled.
+    fn run_chunks<W>(&self, range: Range<usize>, work: W)
+    where
+        W: Fn(Range<usize>) -> bool + Sync,
+    {
+        let next = AtomicUsize::new(range.start);
+        let completed = AtomicUsize::new(0);
+        let total = range.len();
+        let chunk_size = self
+            .chunk_size
+            .unwrap_or_else(|| (total / (self.threads * 16)).clamp(1, 1024));
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                scope.spawn(|| {
+                    while !self.control.is_cancelled() {
+                        let start = next.fetch_add(chunk_size, Ordering::Relaxed);
+                        if start >= range.end {
+                            return;
+                        }
+                        let chunk = start..range.end.min(start.saturating_add(chunk_size));
+                        let size = chunk.len();
+
+                        if !work(chunk) {
+                            return;
+                        }
+
+                        self.report(&completed, size, total);
+                    }
+                });
+            }
+        });
+    }
+
+    // Gives every worker its own copy of `cursor`, the state for `range.start`,
+    // advanced to the worker's first candidate and from there on by the number
+    // of workers. Stops a worker once `work` returns false or the search gets
+    // cancelled.
+    fn run_strided<C, A, W>(&self, range: Range<usize>, cursor: &C, advance: A, work: W)
+    where
+        C: Clone + Sync,
+        A: Fn(&mut C, usize) + Sync,
+        W: Fn(usize, &C) -> bool + Sync,
+    {
+        let completed = AtomicUsize::new(0);
+        let total = range.len();
+        let batch = self.chunk_size.unwrap_or(1024);
+        let (range, advance, work) = (&range, &advance, &work);
+
+        thread::scope(|scope| {
+            for worker in 0..self.threads.min(total) {
+                let completed = &completed;
+                scope.spawn(move || {
+                    let mut cursor = cursor.clone();
+                    advance(&mut cursor, worker);
+                    let mut pending = 0;
+
+                    for candidate in range.clone().skip(worker).step_by(self.threads) {
+                        if self.control.is_cancelled() || !work(candidate, &cursor) {
+                            break;
+                        }
+                        pending += 1;
+                        if pending == batch {
+                            self.report(completed, pending, total);
+                            pending = 0;
+                        }
+                        advance(&mut cursor, self.threads);
+                    }
+                    self.report(completed, pending, total);
+                });
+            }
+        });
+    }
+
+    fn report(&self, completed: &AtomicUsize, size: usize, total: usize) {
+        if size == 0 {
+            return;
+        }
+        self.control.completed.fetch_add(size, Ordering::Relaxed);
+        let completed = completed.fetch_add(size, Ordering::Relaxed) + size;
+        if let Some(progress) = &self.progress {
+            progress(completed, total);
+        }
+    }
+
+    /// Smallest candidate in `range` for which `function` returns a value.
+    /// Threads give up on candidates above the best hit found so far.
+    pub fn find_map_min<T, F>(&self, range: Range<usize>, function: F) -> Option<(usize, T)>
+    where
+        T: Send,
+        F: Fn(usize) -> Option<T> + Sync,
+    {
+        let best = AtomicUsize::new(usize::MAX);
+        let found = Mutex::new(None);
+
+        self.run_chunks(range, |chunk| {
+            // Chunks are claimed in ascending order, so every later chunk is worse.
+            if chunk.start >= best.load(Ordering::Relaxed) {
+                return false;
+            }
+
+            for candidate in chunk {
+                if candidate >= best.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Some(value) = function(candidate) {
+                    let mut found = found.lock().unwrap();
+                    if candidate < best.load(Ordering::Relaxed) {
+                        best.store(candidate, Ordering::Relaxed);
+                        *found = Some((candidate, value));
+                    }
+                    break;
+                }
+            }
+            true
+        });
+
+        found.into_inner().unwrap()
+    }
+
+    pub fn find_min<F>(&self, range: Range<usize>, predicate: F) -> Option<usize>
+    where
+        F: Fn(usize) -> bool + Sync,
+    {
+        self.find_map_min(range, |candidate| predicate(candidate).then_some(()))
+            .map(|(candidate, _)| candidate)
+    }
+
+    /// Any candidate in `range` for which `function` returns a value, stopping
+    /// all threads at the first hit.
+    pub fn find_map_any<T, F>(&self, range: Range<usize>, function: F) -> Option<(usize, T)>
+    where
+        T: Send,
+        F: Fn(usize) -> Option<T> + Sync,
+    {
+        let done = AtomicBool::new(false);
+        let found = Mutex::new(None);
+
+        self.run_chunks(range, |chunk| {
+            for candidate in chunk {
+                if done.load(Ordering::Relaxed) {
+                    return false;
+                }
+                if let Some(value) = function(candidate) {
+                    if !done.swap(true, Ordering::Relaxed) {
+                        *found.lock().unwrap() = Some((candidate, value));
+                    }
+                    return false;
+                }
+            }
+            true
+        });
+
+        found.into_inner().unwrap()
+    }
+
+    /// Like `find_map_min`, for candidates that are cheap to step through but
+    /// expensive to jump to. `function` gets the cursor for the candidate,
+    /// which every thread moves forward with `advance` from `cursor`, the
+    /// cursor for `range.start`.
+    pub fn find_map_min_strided<C, A, T, F>(
+        &self,
+        range: Range<usize>,
+        cursor: C,
+        advance: A,
+        function: F,
+    ) -> Option<(usize, T)>
+    where
+        C: Clone + Sync,
+        A: Fn(&mut C, usize) + Sync,
+        T: Send,
+        F: Fn(&C) -> Option<T> + Sync,
+    {
+        let best = AtomicUsize::new(usize::MAX);
+        let found = Mutex::new(None);
+
+        self.run_strided(range, &cursor, advance, |candidate, cursor| {
+            // Every thread goes through its candidates in ascending order.
+            if candidate >= best.load(Ordering::Relaxed) {
+                return false;
+            }
+            if let Some(value) = function(cursor) {
+                let mut found = found.lock().unwrap();
+                if candidate < best.load(Ordering::Relaxed) {
+                    best.store(candidate, Ordering::Relaxed);
+                    *found = Some((candidate, value));
+                }
+                return false;
+            }
+            true
+        });
+
+        found.into_inner().unwrap()
+    }
+
+    /// Like `find_map_any`, with a cursor per thread as in `find_map_min_strided`.
+    pub fn find_map_any_strided<C, A, T, F>(
+        &self,
+        range: Range<usize>,
+        cursor: C,
+        advance: A,
+        function: F,
+    ) -> Option<(usize, T)>
+    where
+        C: Clone + Sync,
+        A: Fn(&mut C, usize) + Sync,
+        T: Send,
+        F: Fn(&C) -> Option<T> + Sync,
+    {
+        let done = AtomicBool::new(false);
+        let found = Mutex::new(None);
+
+        self.run_strided(range, &cursor, advance, |candidate, cursor| {
+            if done.load(Ordering::Relaxed) {
+                return false;
+            }
+            if let Some(value) = function(cursor) {
+                if !done.swap(true, Ordering::Relaxed) {
+                    *found.lock().unwrap() = Some((candidate, value));
+                }
+                return false;
+            }
+            true
+        });
+
+        found.into_inner().unwrap()
+    }
+
+    /// Maps every item on the pool and folds the results with `reduce`,
+    /// starting from `identity` in every chunk.
+    pub fn map_reduce<I, T, M, R>(
+        &self,
+        items: &[I],
+        identity: impl Fn() -> T + Sync,
+        map: M,
+        reduce: R,
+    ) -> T
+    where
+        I: Sync,
+        T: Send,
+        M: Fn(&I) -> T + Sync,
+        R: Fn(T, T) -> T + Sync,
+    {
+        let results = Mutex::new(Vec::new());
+
+        self.run_chunks(0..items.len(), |chunk| {
+            let value = items[chunk.clone()]
+                .iter()
+                .fold(identity(), |acc, item| reduce(acc, map(item)));
+            results.lock().unwrap().push((chunk.start, value));
+            true
+        });
+
+        // Combine in item order so non-commutative reductions stay deterministic.
+        let mut results = results.into_inner().unwrap();
+        results.sort_by_key(|(start, _)| *start);
+        results
+            .into_iter()
+            .fold(identity(), |acc, (_, value)| reduce(acc, value))
+    }
+}
@@ -0,0 +1,172 @@
+use std::{any::type_name, str::FromStr};
+
+use crate::{char_rows, try_numbers, Errors, Grid};
+
+/// Line of the puzzle input together with its 1-based line number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl Line<'_> {
+    /// Numbers separated by `delimiter`, failing on the first token that
+    /// isn't one.
+    pub fn numbers<T: FromStr>(&self, delimiter: u8) -> Result<Vec<T>, Errors> {
+        try_numbers(self.number, self.text, delimiter)
+    }
+}
+
+/// Block of consecutive non-empty lines, as separated by blank lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+
+    for (number, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(Section {
+                    lines: std::mem::take(&mut current),
+                });
+            }
+        } else {
+            current.push(Line {
+                number: number + 1,
+                text,
+            });
+        }
+    }
+
+    if !current.is_empty() {
+        sections.push(Section { lines: current });
+    }
+
+    sections
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// Returns the lines of a section that has to be exactly `N` lines long.
+    pub fn exact_lines<const N: usize>(&self) -> Result<[Line<'a>; N], Errors> {
+        self.lines
+            .as_slice()
+            .try_into()
+            .map_err(|_| Errors::ParseError {
+                line: self.lines[0].number,
+                column: 1,
+                token: self.lines[0].text.to_string(),
+                expected: format!("section of {N} lines"),
+            })
+    }
+
+    pub fn grid(&self) -> Result<Grid<char>, Errors> {
+        let rows = char_rows(self.lines.iter().map(|line| (line.number, line.text)))?;
+
+        Ok(Grid::from(rows))
+    }
+}
+
+/// Value captured by a `{}` placeholder of a `Template`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl Field<'_> {
+    pub fn parse<T: FromStr>(&self) -> Result<T, Errors> {
+        self.text.parse().map_err(|_| Errors::ParseError {
+            line: self.line,
+            column: self.column,
+            token: self.text.to_string(),
+            expected: type_name::<T>().to_string(),
+        })
+    }
+}
+
+/// Line pattern like `"Button A: X+{}, Y+{}"`, where every `{}` captures a field.
+#[derive(Clone, Debug)]
+pub struct Template<'t> {
+    literals: Vec<&'t str>,
+}
+
+impl<'t> Template<'t> {
+    pub fn new(pattern: &'t str) -> Self {
+        let literals: Vec<&str> = pattern.split("{}").collect();
+
+        assert!(
+            literals
+                .get(1..literals.len() - 1)
+                .unwrap_or_default()
+                .iter()
+                .all(|literal| !literal.is_empty()),
+            "Fields of a template need to be separated by text"
+        );
+
+        Self { literals }
+    }
+
+    pub fn captures<'a, const N: usize>(&self, line: Line<'a>) -> Result<[Field<'a>; N], Errors> {
+        assert_eq!(
+            self.literals.len() - 1,
+            N,
+            "Template has a different number of fields"
+        );
+
+        let error = |offset: usize, expected: String| Errors::ParseError {
+            line: line.number,
+            column: line.text[..offset].chars().count() + 1,
+            token: line.text[offset..].to_string(),
+            expected,
+        };
+
+        let mut offset = self.literals[0].len();
+        if !line.text.starts_with(self.literals[0]) {
+            return Err(error(0, format!("`{}`", self.literals[0])));
+        }
+
+        let mut fields = Vec::with_capacity(N);
+        for literal in &self.literals[1..] {
+            let rest = &line.text[offset..];
+            let length = if literal.is_empty() {
+                rest.len()
+            } else {
+                rest.find(literal)
+                    .ok_or_else(|| error(offset, format!("`{literal}`")))?
+            };
+
+            fields.push(Field {
+                line: line.number,
+                column: line.text[..offset].chars().count() + 1,
+                text: &rest[..length],
+            });
+            offset += length + literal.len();
+        }
+
+        if offset != line.text.len() {
+            return Err(error(offset, "end of line".to_string()));
+        }
+
+        Ok(fields.try_into().unwrap())
+    }
+
+    /// Captures all fields of `line` and parses them as `T`.
+    pub fn parse<T: FromStr, const N: usize>(&self, line: Line) -> Result<[T; N], Errors> {
+        let fields = self.captures::<N>(line)?;
+        let mut values = Vec::with_capacity(N);
+        for field in fields {
+            values.push(field.parse()?);
+        }
+
+        Ok(values.try_into().ok().unwrap())
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use utils_2024::{bruteforce, bruteforce_min, Bruteforce};
+
+#[derive(Clone)]
+struct Counter(usize);
+
+impl Bruteforce for Counter {
+    fn skip(&mut self, steps: usize) {
+        self.0 += steps;
+    }
+}
+
+// Counts how many steps all copies were skipped by together.
+#[derive(Clone)]
+struct Stepper {
+    value: usize,
+    skipped: Arc<AtomicUsize>,
+}
+
+impl Bruteforce for Stepper {
+    fn skip(&mut self, steps: usize) {
+        self.value += steps;
+        self.skipped.fetch_add(steps, Ordering::Relaxed);
+    }
+}
+
+const THREADS: [usize; 5] = [1, 2, 3, 4, 8];
+
+// Makes the smallest hit the slowest one, so a thread finding a later hit
+// finishes first whenever more than one thread is running.
+fn slow_multiples(Counter(value): &Counter, start: usize, divisor: usize) -> Option<usize> {
+    if *value < start || value % divisor != 0 {
+        return None;
+    }
+    if *value == start {
+        thread::sleep(Duration::from_millis(50));
+    }
+    Some(value * 10)
+}
+
+#[test]
+fn min_returns_first_hit_for_every_thread_count() {
+    for threads in THREADS {
+        let result = bruteforce_min(
+            |counter| slow_multiples(counter, 3000, 3),
+            Counter(0),
+            threads,
+        );
+        assert_eq!(result, Some((3000, 30000)), "{threads} threads");
+    }
+}
+
+#[test]
+fn min_counts_steps_from_the_starting_parameter() {
+    for threads in THREADS {
+        let result = bruteforce_min(
+            |counter| slow_multiples(counter, 42, 7),
+            Counter(40),
+            threads,
+        );
+        assert_eq!(result, Some((2, 420)), "{threads} threads");
+    }
+}
+
+#[test]
+fn min_finds_a_hit_at_zero_steps() {
+    for threads in THREADS {
+        let result = bruteforce_min(|Counter(value)| Some(*value), Counter(5), threads);
+        assert_eq!(result, Some((0, 5)), "{threads} threads");
+    }
+}
+
+#[test]
+fn min_ignores_later_hits_found_first() {
+    // Hits in every chunk, but only the one with the fewest steps counts.
+    for threads in THREADS {
+        let result = bruteforce_min(
+            |Counter(value)| {
+                if *value == 1500 {
+                    thread::sleep(Duration::from_millis(20));
+                    Some(1)
+                } else {
+                    (*value > 1500 && value % 100 == 0).then_some(2)
+                }
+            },
+            Counter(0),
+            threads,
+        );
+        assert_eq!(result, Some((1500, 1)), "{threads} threads");
+    }
+}
+
+#[test]
+fn any_returns_some_hit() {
+    for threads in THREADS {
+        let result = bruteforce(
+            |counter| slow_multiples(counter, 3000, 3),
+            Counter(0),
+            threads,
+        );
+        assert!(
+            result.is_some_and(|value| value >= 30000 && value % 30 == 0),
+            "{threads} threads: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn any_skips_each_copy_by_the_thread_count() {
+    const HIT: usize = 20000;
+
+    for threads in THREADS {
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let stepper = Stepper {
+            value: 0,
+            skipped: skipped.clone(),
+        };
+
+        let result = bruteforce(
+            |stepper| (stepper.value == HIT).then_some(1),
+            stepper,
+            threads,
+        );
+        assert_eq!(result, Some(1), "{threads} threads");
+        // A single thread steps straight to the hit. Other threads may run
+        // ahead a little, but skipping every candidate from the start would
+        // take about HIT² / 2 steps.
+        let skipped = skipped.load(Ordering::Relaxed);
+        if threads == 1 {
+            assert_eq!(skipped, HIT);
+        }
+        assert!(
+            skipped < HIT * HIT / 20,
+            "{threads} threads: {skipped} steps"
+        );
+    }
+}
+
+#[test]
+fn min_skips_each_copy_by_the_thread_count() {
+    const HIT: usize = 20000;
+
+    for threads in THREADS {
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let stepper = Stepper {
+            value: 0,
+            skipped: skipped.clone(),
+        };
+
+        let result = bruteforce_min(
+            |stepper| (stepper.value >= HIT).then_some(1),
+            stepper,
+            threads,
+        );
+        assert_eq!(result, Some((HIT, 1)), "{threads} threads");
+        let skipped = skipped.load(Ordering::Relaxed);
+        assert!(
+            skipped < HIT * HIT / 20,
+            "{threads} threads: {skipped} steps"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use utils_2024::{Direction, Grid, Point, Position};
+
+// 3 wide and 2 high, with the cell value being its index.
+fn grid() -> Grid<usize> {
+    Grid::from(vec![vec![0, 1, 2], vec![3, 4, 5]])
+}
+
+fn sorted(positions: impl Iterator<Item = Position>) -> Vec<Position> {
+    let mut positions: Vec<Position> = positions.collect();
+    positions.sort_unstable();
+    positions
+}
+
+#[test]
+fn stores_rows_in_order() {
+    let grid = grid();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 3);
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.find(&4), Some((1, 1)));
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+}
+
+#[test]
+fn offsets_stay_inside_the_grid() {
+    let grid = grid();
+
+    assert_eq!(grid.offset((0, 0), Direction::East), Some((0, 1)));
+    assert_eq!(grid.offset((0, 0), Direction::North), None);
+    assert_eq!(grid.offset((0, 0), Direction::West), None);
+    assert_eq!(grid.offset((1, 2), Direction::South), None);
+    assert_eq!(grid.offset((1, 2), Direction::East), None);
+    assert_eq!(grid.offset((1, 2), Point::new(-1, -2)), Some((0, 0)));
+    assert_eq!(grid.offset((0, 0), Point::new(0, 3)), None);
+}
+
+#[test]
+fn leaves_out_neighbours_past_the_edges() {
+    let grid = grid();
+
+    assert_eq!(sorted(grid.neighbours((0, 0))), [(0, 1), (1, 0)]);
+    assert_eq!(sorted(grid.neighbours((1, 1))), [(0, 1), (1, 0), (1, 2)]);
+    assert_eq!(sorted(grid.neighbours8((0, 0))), [(0, 1), (1, 0), (1, 1)]);
+    assert_eq!(
+        sorted(grid.neighbours8((1, 1))),
+        [(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+    );
+    assert_eq!(sorted(grid.neighbours8((0, 2))), [(0, 1), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn handles_empty_grids() {
+    let grid: Grid<u8> = Grid::from(Vec::new());
+
+    assert_eq!((grid.width(), grid.height()), (0, 0));
+    assert_eq!(grid.get((0, 0)), None);
+    assert_eq!(grid.rows().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "All rows of a grid need the same length")]
+fn rejects_ragged_rows() {
+    let _ = Grid::from(vec![vec![0, 1], vec![2]]);
+}
@@ -0,0 +1,93 @@
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+use utils_2024::input::{load_arg, Args, Inputs};
+
+const KNOWN: [&str; 2] = ["--render", "--blinks="];
+
+// Writes `content` to a file of its own in the temporary directory.
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("utils_2024_input_{}", std::process::id()));
+    let path = dir.join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn reads_flags_and_the_input_path() {
+    let path = temp_file("flags.txt", "1 2 3\n");
+    let path = path.to_str().unwrap();
+    let parsed =
+        Args::from_args(2024, 11, &KNOWN, args(&["--blinks=75", path, "--render"])).unwrap();
+
+    assert_eq!(parsed.input, "1 2 3\n");
+    assert!(parsed.flag("--render"));
+    assert!(!parsed.flag("--blinks"));
+    assert_eq!(parsed.value("--blinks"), Some("75"));
+    assert_eq!(parsed.value("--render"), None);
+}
+
+#[test]
+fn rejects_unknown_flags() {
+    let Err(err) = Args::from_args(2024, 11, &KNOWN, args(&["--blink=75"])) else {
+        panic!("Expected the flag to be rejected");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Unknown flag --blink=75, expected one of --render, --blinks="
+    );
+
+    // Flags taking a value can't be passed without one and vice versa.
+    assert!(Args::from_args(2024, 11, &KNOWN, args(&["--blinks"])).is_err());
+    assert!(Args::from_args(2024, 11, &KNOWN, args(&["--render=yes"])).is_err());
+}
+
+#[test]
+fn rejects_more_than_one_input() {
+    let Err(err) = Args::from_args(2024, 11, &KNOWN, args(&["example", "input"])) else {
+        panic!("Expected the second input to be rejected");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Unexpected argument input, only the input can be given"
+    );
+}
+
+#[test]
+fn reports_missing_paths_as_given() {
+    for path in ["missing/in.txt", "in.txt"] {
+        let err = load_arg(2024, 11, Some(path)).unwrap_err().to_string();
+        assert!(
+            err.starts_with(&format!("Unable to read {path}: ")),
+            "{err}"
+        );
+        assert!(!err.contains("AOC_INPUT_DIR"), "{err}");
+    }
+}
+
+#[test]
+fn looks_up_inputs_by_name() {
+    let path = temp_file("2024/day11/example.txt", "125 17\n");
+    let inputs = Inputs::new(path.ancestors().nth(3).unwrap());
+
+    assert_eq!(inputs.path(2024, 11, "example"), path);
+    assert_eq!(inputs.read(2024, 11, "example").unwrap(), "125 17\n");
+
+    let err = inputs.read(2024, 11, "missing").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    let err = err.to_string();
+    assert!(err.ends_with("(set AOC_INPUT_DIR or pass a path to use a different input)"));
+}
+
+#[test]
+fn tells_unreadable_inputs_from_missing_ones() {
+    let path = temp_file("2024/day12/input.txt/placeholder", "");
+    let inputs = Inputs::new(path.ancestors().nth(4).unwrap());
+
+    let err = inputs.read(2024, 12, "input").unwrap_err();
+    assert_ne!(err.kind(), ErrorKind::NotFound);
+    assert!(!err.to_string().contains("AOC_INPUT_DIR"), "{err}");
+}
@@ -0,0 +1,43 @@
+use utils_2024::{Errors, StringHandling, StringVecHandling};
+
+#[test]
+fn iterates_numbers_of_any_type_per_line() {
+    let lines = "1,-2\n\n300,4\n".get_lines();
+
+    let small: Vec<Vec<i16>> = lines.iter_numbers_in_line(b',').collect();
+    assert_eq!(small, [vec![1, -2], vec![300, 4]]);
+
+    // Lenient like `numbers_in_line`: tokens of the wrong type are left out.
+    let unsigned: Vec<Vec<u8>> = lines.iter_numbers_in_line(b',').collect();
+    assert_eq!(unsigned, [vec![1], vec![4]]);
+    assert_eq!(lines.numbers_in_line::<u8>(b','), unsigned);
+}
+
+#[test]
+fn iterates_numbers_with_an_error_per_bad_line() {
+    let lines = "1,2\n\n3,x\n5,6\n".get_lines();
+    let numbers: Vec<Result<Vec<u64>, Errors>> = lines.try_iter_numbers_in_line(b',').collect();
+
+    assert_eq!(
+        numbers,
+        [
+            Ok(vec![1, 2]),
+            Err(Errors::ParseError {
+                line: 3,
+                column: 3,
+                token: "x".to_string(),
+                expected: "u64".to_string(),
+            }),
+            Ok(vec![5, 6]),
+        ]
+    );
+    assert_eq!(
+        lines.try_numbers_in_line::<u64>(b','),
+        Err(Errors::ParseError {
+            line: 3,
+            column: 3,
+            token: "x".to_string(),
+            expected: "u64".to_string(),
+        })
+    );
+}
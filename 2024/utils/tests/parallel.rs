@@ -0,0 +1,94 @@
+use std::sync::Mutex;
+use utils_2024::{Control, Executor};
+
+const THREADS: [usize; 5] = [1, 2, 3, 4, 8];
+
+#[test]
+fn map_reduce_combines_chunks_in_item_order() {
+    let items: Vec<usize> = (0..500).collect();
+    let expected: String = items.iter().map(|item| (item % 10).to_string()).collect();
+
+    for threads in THREADS {
+        for chunk_size in [1, 7, 64, 1000] {
+            let result = Executor::new()
+                .threads(threads)
+                .chunk_size(chunk_size)
+                .map_reduce(
+                    &items,
+                    String::new,
+                    |item| (item % 10).to_string(),
+                    |acc, value| acc + &value,
+                );
+            assert_eq!(
+                result, expected,
+                "{threads} threads, chunks of {chunk_size}"
+            );
+        }
+    }
+}
+
+#[test]
+fn map_reduce_of_no_items_is_the_identity() {
+    let result = Executor::new().map_reduce(&[] as &[usize], || 5, |&item| item, |a, b| a + b);
+    assert_eq!(result, 5);
+}
+
+#[test]
+fn cancelling_stops_a_search_early() {
+    for threads in THREADS {
+        let control = Control::new();
+        let result = Executor::new()
+            .threads(threads)
+            .chunk_size(10)
+            .control(control.clone())
+            .find_min(0..usize::MAX, |candidate| {
+                if candidate >= 1000 {
+                    control.cancel();
+                }
+                false
+            });
+
+        assert_eq!(result, None, "{threads} threads");
+        assert!(control.is_cancelled());
+        // Every thread finishes at most the chunk it is working on.
+        assert!(
+            control.completed() <= 1000 + threads * 10,
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn cancelled_control_skips_all_work() {
+    let control = Control::new();
+    control.cancel();
+
+    let result = Executor::new()
+        .control(control.clone())
+        .find_map_any(0..100, Some);
+    assert_eq!(result, None);
+    assert_eq!(control.completed(), 0);
+}
+
+#[test]
+fn progress_reports_every_chunk() {
+    let items = vec![1; 1000];
+
+    for threads in THREADS {
+        let reports = Mutex::new(Vec::new());
+        let control = Control::new();
+        let sum = Executor::new()
+            .threads(threads)
+            .chunk_size(10)
+            .control(control.clone())
+            .on_progress(|completed, total| reports.lock().unwrap().push((completed, total)))
+            .map_reduce(&items, || 0, |&item| item, |a, b| a + b);
+
+        let mut reports = reports.into_inner().unwrap();
+        reports.sort_unstable();
+        let expected: Vec<_> = (1..=100).map(|chunk| (chunk * 10, 1000)).collect();
+        assert_eq!(sum, 1000);
+        assert_eq!(reports, expected, "{threads} threads");
+        assert_eq!(control.completed(), 1000);
+    }
+}
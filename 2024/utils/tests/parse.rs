@@ -0,0 +1,262 @@
+use utils_2024::{Errors, Field, Line, StringHandling, StringVecHandling, Template};
+
+fn parse_error(line: usize, column: usize, token: &str, expected: &str) -> Errors {
+    Errors::ParseError {
+        line,
+        column,
+        token: token.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+#[test]
+fn reports_the_column_of_bad_numbers() {
+    let lines = "1 2 3\n4  five 6\n".get_lines();
+
+    assert_eq!(
+        lines.try_numbers_in_line::<u32>(b' '),
+        Err(parse_error(2, 4, "five", "u32"))
+    );
+    assert_eq!(
+        lines.try_numbers_in_column::<u32>(b' '),
+        Err(parse_error(2, 4, "five", "u32"))
+    );
+}
+
+#[test]
+fn skips_blanks_around_numbers() {
+    let lines = " 1 ,2\n\n3,  4 \n".get_lines();
+
+    assert_eq!(
+        lines.try_numbers_in_line::<u8>(b','),
+        Ok(vec![vec![1, 2], vec![3, 4]])
+    );
+    assert_eq!(
+        lines.try_numbers_in_column::<u8>(b','),
+        Ok(vec![vec![1, 3], vec![2, 4]])
+    );
+}
+
+#[test]
+fn counts_columns_in_characters() {
+    let lines = "ä,1,-1\n".get_lines();
+
+    assert_eq!(
+        lines.try_numbers_in_line::<u8>(b','),
+        Err(parse_error(1, 1, "ä", "u8"))
+    );
+    assert_eq!(
+        "1,ä\n".get_lines().try_numbers_in_line::<u8>(b','),
+        Err(parse_error(1, 3, "ä", "u8"))
+    );
+    assert_eq!(
+        "ä,1,-1\n".get_lines().try_split_with_key::<String, u8>(','),
+        Err(parse_error(1, 3, "1,-1", "u8"))
+    );
+}
+
+#[test]
+fn splits_keys_from_values() {
+    let lines = "190: 10 19\n\n3267: 81 40 27\n".get_lines();
+
+    assert_eq!(
+        lines.try_split_with_key::<u64, u32>(':'),
+        Ok(vec![(190, vec![10, 19]), (3267, vec![81, 40, 27])])
+    );
+}
+
+#[test]
+fn reports_bad_keys_and_values() {
+    assert_eq!(
+        "190 10 19\n"
+            .get_lines()
+            .try_split_with_key::<u64, u32>(':'),
+        Err(parse_error(1, 10, "190 10 19", "`:`"))
+    );
+    assert_eq!(
+        " x: 10\n".get_lines().try_split_with_key::<u64, u32>(':'),
+        Err(parse_error(1, 2, "x", "u64"))
+    );
+    assert_eq!(
+        "190: 10  y\n"
+            .get_lines()
+            .try_split_with_key::<u64, u32>(':'),
+        Err(parse_error(1, 10, "y", "u32"))
+    );
+}
+
+#[test]
+fn reads_digit_grids() {
+    assert_eq!(
+        "012\n345\n".get_lines().try_digits_grid(),
+        Ok(vec![vec![0, 1, 2], vec![3, 4, 5]])
+    );
+    assert_eq!(
+        "012\n3a5\n".get_lines().try_digits_grid(),
+        Err(parse_error(2, 2, "a", "digit"))
+    );
+}
+
+#[test]
+fn rejects_ragged_rows() {
+    assert_eq!(
+        "012\n34\n".get_lines().try_digits_grid(),
+        Err(parse_error(2, 3, "34", "row of 3 characters"))
+    );
+    assert_eq!(
+        "ab\nabc\n".get_lines().try_lines_as_chars(),
+        Err(parse_error(2, 3, "abc", "row of 2 characters"))
+    );
+    assert_eq!(
+        "ab\ncd\n".get_lines().try_lines_as_chars(),
+        Ok(vec![vec!['a', 'b'], vec!['c', 'd']])
+    );
+}
+
+fn line(text: &str) -> Line<'_> {
+    Line { number: 3, text }
+}
+
+#[test]
+fn captures_template_fields() {
+    let button = Template::new("Button A: X+{}, Y+{}");
+
+    assert_eq!(
+        button.captures(line("Button A: X+94, Y+34")),
+        Ok([
+            Field {
+                line: 3,
+                column: 13,
+                text: "94",
+            },
+            Field {
+                line: 3,
+                column: 19,
+                text: "34",
+            },
+        ])
+    );
+    assert_eq!(button.parse(line("Button A: X+94, Y+34")), Ok([94, 34]));
+}
+
+#[test]
+fn reports_missing_template_literals() {
+    let button = Template::new("Button A: X+{}, Y+{}");
+
+    assert_eq!(
+        button.captures::<2>(line("Button B: X+94, Y+34")),
+        Err(parse_error(3, 1, "Button B: X+94, Y+34", "`Button A: X+`"))
+    );
+    assert_eq!(
+        button.captures::<2>(line("Button A: X+94 Y+34")),
+        Err(parse_error(3, 13, "94 Y+34", "`, Y+`"))
+    );
+}
+
+#[test]
+fn reports_text_after_the_template() {
+    let pair = Template::new("({}, {})");
+
+    assert_eq!(
+        pair.captures::<2>(line("(1, 2)x")),
+        Err(parse_error(3, 7, "x", "end of line"))
+    );
+}
+
+#[test]
+fn keeps_whitespace_in_fields() {
+    let button = Template::new("Button A: X+{}, Y+{}");
+
+    // A trailing field runs to the end of the line, blanks included.
+    let [_, y] = button.captures(line("Button A: X+1, Y+2 ")).unwrap();
+    assert_eq!(y.text, "2 ");
+    assert_eq!(
+        button.parse::<u32, 2>(line("Button A: X+1, Y+2 ")),
+        Err(parse_error(3, 18, "2 ", "u32"))
+    );
+}
+
+#[test]
+fn captures_empty_fields_but_fails_to_parse_them() {
+    let button = Template::new("Button A: X+{}, Y+{}");
+
+    let [x, _] = button.captures(line("Button A: X+, Y+2")).unwrap();
+    assert_eq!(x.text, "");
+    assert_eq!(
+        button.parse::<u32, 2>(line("Button A: X+, Y+2")),
+        Err(parse_error(3, 13, "", "u32"))
+    );
+}
+
+#[test]
+fn splits_sections_at_blank_lines() {
+    let input = "a\nb\n\n\n c\n  \nd 1 2\n";
+    let sections = input.sections();
+
+    let lines: Vec<Vec<Line>> = sections
+        .iter()
+        .map(|section| section.lines().to_vec())
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            vec![
+                Line {
+                    number: 1,
+                    text: "a"
+                },
+                Line {
+                    number: 2,
+                    text: "b"
+                }
+            ],
+            vec![Line {
+                number: 5,
+                text: " c"
+            }],
+            vec![Line {
+                number: 7,
+                text: "d 1 2",
+            }],
+        ]
+    );
+
+    let [last] = sections[2].exact_lines().unwrap();
+    assert_eq!(last.numbers::<u8>(b' '), Err(parse_error(7, 1, "d", "u8")));
+    assert!("\n \n".sections().is_empty());
+}
+
+#[test]
+fn rejects_sections_of_the_wrong_length() {
+    let sections = "a\nb\n\nc\n".sections();
+
+    assert_eq!(
+        sections[0].exact_lines::<1>(),
+        Err(parse_error(1, 1, "a", "section of 1 lines"))
+    );
+    assert_eq!(
+        sections[1].exact_lines::<2>(),
+        Err(parse_error(4, 1, "c", "section of 2 lines"))
+    );
+}
+
+#[test]
+fn reads_section_grids() {
+    let sections = "x\n\nab\ncd\n\nab\nc\n".sections();
+
+    let grid = sections[1].grid().unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(grid[(1, 0)], 'c');
+    assert_eq!(
+        sections[2].grid(),
+        Err(parse_error(7, 2, "c", "row of 2 characters"))
+    );
+}
+
+#[test]
+fn displays_parse_errors_with_their_position() {
+    assert_eq!(
+        parse_error(2, 4, "five", "u32").to_string(),
+        "Line 2, column 4: expected u32, found `five`"
+    );
+}
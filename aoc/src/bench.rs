@@ -0,0 +1,209 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs::{read_to_string, write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{format_time, inputs, print_table};
+use aoc::days::*;
+
+type Results = BTreeMap<(u16, u8, String), Duration>;
+
+struct Measurement {
+    step: String,
+    median: Duration,
+    min: Duration,
+}
+
+pub struct BenchOptions {
+    year: u16,
+    day: Option<u8>,
+    samples: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+}
+
+pub fn parse_args(args: &[String]) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions {
+        year: 2024,
+        day: None,
+        samples: 10,
+        save: None,
+        compare: None,
+        input_dir: None,
+    };
+    let mut year = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => {
+                let samples = args.next().ok_or("Missing value for --samples")?;
+                options.samples = match samples.parse() {
+                    Ok(0) | Err(_) => return Err(format!("Invalid sample count: {samples}")),
+                    Ok(samples) => samples,
+                };
+            }
+            "--save" => {
+                let path = args.next().ok_or("Missing value for --save")?;
+                options.save = Some(PathBuf::from(path));
+            }
+            "--compare" => {
+                let path = args.next().ok_or("Missing value for --compare")?;
+                options.compare = Some(PathBuf::from(path));
+            }
+            "--input-dir" => {
+                let dir = args.next().ok_or("Missing value for --input-dir")?;
+                options.input_dir = Some(PathBuf::from(dir));
+            }
+            value if year.is_none() => {
+                year = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid year: {value}"))?,
+                );
+            }
+            value if options.day.is_none() => {
+                options.day = Some(value.parse().map_err(|_| format!("Invalid day: {value}"))?);
+            }
+            value => return Err(format!("Unexpected argument: {value}")),
+        }
+    }
+
+    options.year = year.unwrap_or(options.year);
+    Ok(options)
+}
+
+fn median(times: &mut [Duration]) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn bench_day(day: &Day, input: &str, samples: usize) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    let mut steps: Vec<(String, Vec<Duration>)> = Vec::new();
+
+    for _ in 0..samples {
+        let run = (day.run)(input, &[1, 2])?;
+        let times = [("parse".to_string(), run.parse)].into_iter().chain(
+            run.answers
+                .iter()
+                .map(|answer| (format!("part{}", answer.part), answer.time)),
+        );
+
+        for (i, (step, time)) in times.enumerate() {
+            match steps.get_mut(i) {
+                Some((_, times)) => times.push(time),
+                None => steps.push((step, vec![time])),
+            }
+        }
+    }
+
+    Ok(steps
+        .into_iter()
+        .map(|(step, mut times)| Measurement {
+            min: *times.iter().min().unwrap(),
+            median: median(&mut times),
+            step,
+        })
+        .collect())
+}
+
+// One measurement per line: `<year> <day> <step> <median in nanoseconds>`.
+fn load_results(path: &PathBuf) -> Result<Results, Box<dyn Error>> {
+    let content =
+        read_to_string(path).map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+    let mut results = Results::new();
+
+    for (number, line) in content.lines().enumerate() {
+        let invalid = || {
+            format!(
+                "{}:{}: invalid benchmark result",
+                path.display(),
+                number + 1
+            )
+        };
+        let [year, day, step, nanos] = line
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| invalid())?;
+
+        results.insert(
+            (
+                year.parse().map_err(|_| invalid())?,
+                day.parse().map_err(|_| invalid())?,
+                step.to_string(),
+            ),
+            Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+        );
+    }
+
+    Ok(results)
+}
+
+fn save_results(path: &PathBuf, results: &Results) -> Result<(), Box<dyn Error>> {
+    let content: String = results
+        .iter()
+        .map(|((year, day, step), time)| format!("{year} {day} {step} {}\n", time.as_nanos()))
+        .collect();
+
+    write(path, content).map_err(|err| format!("Unable to write {}: {err}", path.display()))?;
+    Ok(())
+}
+
+fn change(old: Duration, new: Duration) -> String {
+    let percent = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+    format!("{percent:+.1}%")
+}
+
+pub fn run(options: BenchOptions) -> Result<(), Box<dyn Error>> {
+    let baseline = options.compare.as_ref().map(load_results).transpose()?;
+    let inputs = inputs(options.input_dir);
+
+    let days = DAYS
+        .iter()
+        .filter(|day| day.year == options.year && options.day.is_none_or(|d| d == day.day));
+
+    let mut results = Results::new();
+    let mut rows = Vec::new();
+    for day in days {
+        let Ok(input) = inputs.read(day.year, day.day, "input") else {
+            eprintln!("{} day {}: missing input, skipped", day.year, day.day);
+            continue;
+        };
+
+        for measurement in bench_day(day, &input, options.samples)? {
+            let key = (day.year, day.day, measurement.step.clone());
+            let mut row = vec![
+                day.year.to_string(),
+                format!("{:>3}", day.day),
+                measurement.step,
+                format_time(measurement.median),
+                format_time(measurement.min),
+            ];
+            if let Some(baseline) = &baseline {
+                row.push(match baseline.get(&key) {
+                    Some(&old) => change(old, measurement.median),
+                    None => "new".to_string(),
+                });
+            }
+            rows.push(row);
+            results.insert(key, measurement.median);
+        }
+    }
+
+    let mut header = vec!["Year", "Day", "Step", "Median", "Min"];
+    if baseline.is_some() {
+        header.push("Change");
+    }
+    print_table(&header, &rows);
+
+    if let Some(path) = &options.save {
+        save_results(path, &results)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+use utils_2024::Solution;
+
+pub type RunFn = fn(&str, &[u8]) -> Result<Run, Box<dyn Error>>;
+
+/// Answers of a single run along with the wall-clock time of every step.
+pub struct Run {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    pub time: Duration,
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub run: RunFn,
+    /// Like `run`, but with the grid sizes and thresholds of the puzzle
+    /// examples for days whose real inputs use larger ones.
+    pub example: RunFn,
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Run, Box<dyn Error>> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                1 => S::part1(&parsed).to_string(),
+                _ => S::part2(&parsed).to_string(),
+            };
+            Answer {
+                part,
+                value,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse, answers })
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        day!($year, $day, $solution, $solution)
+    };
+    ($year:literal, $day:literal, $solution:ty, $example:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            run: run::<$solution>,
+            example: run::<$example>,
+        }
+    };
+}
+
+/// Calls `$callback!` with every registered day as
+/// `name: year, day, Solution[, ExampleSolution];`, so that tests can be
+/// generated per day.
+#[macro_export]
+macro_rules! for_each_day {
+    ($callback:ident) => {
+        $callback! {
+            y2023_day01: 2023, 1, day01_2023::Day01;
+            y2024_day01: 2024, 1, day01_2024::Day01;
+            y2024_day02: 2024, 2, day02_2024::Day02;
+            y2024_day03: 2024, 3, day03_2024::Day03;
+            y2024_day04: 2024, 4, day04_2024::Day04;
+            y2024_day05: 2024, 5, day05_2024::Day05;
+            y2024_day06: 2024, 6, day06_2024::Day06;
+            y2024_day07: 2024, 7, day07_2024::Day07;
+            y2024_day08: 2024, 8, day08_2024::Day08;
+            y2024_day09: 2024, 9, day09_2024::Day09;
+            y2024_day10: 2024, 10, day10_2024::Day10;
+            y2024_day11: 2024, 11, day11_2024::Day11;
+            y2024_day12: 2024, 12, day12_2024::Day12;
+            y2024_day13: 2024, 13, day13_2024::Day13;
+            y2024_day14: 2024, 14, day14_2024::Day14, day14_2024::Day14<11, 7>;
+            y2024_day15: 2024, 15, day15_2024::Day15;
+            y2024_day16: 2024, 16, day16_2024::Day16;
+            y2024_day17: 2024, 17, day17_2024::Day17;
+            y2024_day18: 2024, 18, day18_2024::Day18, day18_2024::Day18<12>;
+            y2024_day19: 2024, 19, day19_2024::Day19;
+            y2024_day20: 2024, 20, day20_2024::Day20, day20_2024::Day20<50>;
+            y2024_day21: 2024, 21, day21_2024::Day21;
+            y2024_day22: 2024, 22, day22_2024::Day22;
+            y2024_day23: 2024, 23, day23_2024::Day23;
+            y2024_day24: 2024, 24, day24_2024::Day24;
+            y2024_day25: 2024, 25, day25_2024::Day25;
+        }
+    };
+}
+
+macro_rules! day_table {
+    ($($name:ident: $year:literal, $day:literal, $($solution:ty),+;)+) => {
+        pub const DAYS: &[Day] = &[$(day!($year, $day, $($solution),+)),+];
+    };
+}
+
+for_each_day!(day_table);
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
@@ -0,0 +1,235 @@
+use aoc::days::*;
+use std::{env, error::Error, io, path::PathBuf, process, time::Duration};
+use utils_2024::input::{self, Inputs};
+
+mod bench;
+
+const USAGE: &str = "Usage: aoc run <year> <day> [--part <1|2>] [--input <path|->] [--name <name>]
+                              [--input-dir <dir>] [--time]
+       aoc run --all [--input-dir <dir>] [--time]
+       aoc bench [<year> [<day>]] [--input-dir <dir>] [--samples <n>] [--save <path>]
+                 [--compare <path>]
+
+Inputs are read from <dir>/<year>/dayNN/<name>.txt, where <dir> is taken from
+--input-dir, $AOC_INPUT_DIR or defaults to the workspace root, and <name> is
+`input` unless --name is given. Names starting with `example` run with the
+grid sizes and thresholds of the puzzle examples.";
+
+#[derive(Default)]
+struct Options {
+    all: bool,
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    name: Option<String>,
+    input_dir: Option<PathBuf>,
+    time: bool,
+}
+
+enum Command {
+    Run(Options),
+    Bench(bench::BenchOptions),
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => Ok(Command::Run(parse_run_args(&args[1..])?)),
+        Some("bench") => Ok(Command::Bench(bench::parse_args(&args[1..])?)),
+        _ => Err("Expected the `run` or `bench` command".to_string()),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--time" => options.time = true,
+            "--part" => {
+                let part = args.next().ok_or("Missing value for --part")?;
+                options.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part: {part}")),
+                };
+            }
+            "--input" => {
+                let path = args.next().ok_or("Missing value for --input")?;
+                options.input = Some(PathBuf::from(path));
+            }
+            "--name" => {
+                let name = args.next().ok_or("Missing value for --name")?;
+                options.name = Some(name.clone());
+            }
+            "--input-dir" => {
+                let dir = args.next().ok_or("Missing value for --input-dir")?;
+                options.input_dir = Some(PathBuf::from(dir));
+            }
+            value if options.year.is_none() => {
+                options.year = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid year: {value}"))?,
+                );
+            }
+            value if options.day.is_none() => {
+                options.day = Some(value.parse().map_err(|_| format!("Invalid day: {value}"))?);
+            }
+            value => return Err(format!("Unexpected argument: {value}")),
+        }
+    }
+
+    if options.all
+        && (options.year.is_some()
+            || options.part.is_some()
+            || options.input.is_some()
+            || options.name.is_some())
+    {
+        return Err("--all can't be combined with a day, --part, --input or --name".to_string());
+    } else if options.input.is_some() && (options.name.is_some() || options.input_dir.is_some()) {
+        return Err("--input can't be combined with --name or --input-dir".to_string());
+    } else if !options.all && (options.year.is_none() || options.day.is_none()) {
+        return Err("Expected a year and a day".to_string());
+    }
+
+    Ok(options)
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|title| title.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+
+    format_row(header.to_vec());
+    for row in rows {
+        format_row(row.iter().map(String::as_str).collect());
+    }
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{time:.2?}")
+}
+
+fn inputs(dir: Option<PathBuf>) -> Inputs {
+    dir.map(Inputs::new).unwrap_or_else(Inputs::from_env)
+}
+
+fn run_day(run: RunFn, part: Option<u8>, input: &str, time: bool) -> Result<(), Box<dyn Error>> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let run = run(input, &parts)?;
+    if time {
+        println!("Parse: {}", format_time(run.parse));
+    }
+    for answer in run.answers {
+        if time {
+            println!(
+                "Part {}: {} ({})",
+                answer.part,
+                answer.value,
+                format_time(answer.time)
+            );
+        } else {
+            println!("Part {}: {}", answer.part, answer.value);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_all(inputs: &Inputs, time: bool) {
+    let mut rows = Vec::new();
+
+    for day in DAYS {
+        let mut row = vec![day.year.to_string(), format!("{:>3}", day.day)];
+        match inputs.read(day.year, day.day, "input") {
+            Ok(input) => match (day.run)(&input, &[1, 2]) {
+                Ok(run) => {
+                    row.extend(run.answers.iter().map(|answer| answer.value.clone()));
+                    if time {
+                        row.push(format_time(run.parse));
+                        row.extend(run.answers.iter().map(|answer| format_time(answer.time)));
+                    }
+                }
+                Err(err) => row.push(format!("error: {err}")),
+            },
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                row.extend(["missing input".to_string(), "missing input".to_string()])
+            }
+            Err(err) => row.push(format!("error: {err}")),
+        }
+        rows.push(row);
+    }
+
+    let mut header = vec!["Year", "Day", "Part 1", "Part 2"];
+    if time {
+        header.extend(["Parse", "Time 1", "Time 2"]);
+    }
+    print_table(&header, &rows);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match parse_args(&args) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Bench(options)) => {
+            if let Err(err) = bench::run(options) {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+            return;
+        }
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if options.all {
+        run_all(&inputs(options.input_dir), options.time);
+        return;
+    }
+
+    let (year, day) = (options.year.unwrap(), options.day.unwrap());
+    let Some(day) = find(year, day) else {
+        eprintln!("No solution registered for {year} day {day}");
+        process::exit(1);
+    };
+
+    let name = options.name.as_deref().unwrap_or("input");
+    let input = match &options.input {
+        Some(path) => input::read_path(path),
+        None => inputs(options.input_dir).read(day.year, day.day, name),
+    };
+    let run = match name.starts_with("example") {
+        true => day.example,
+        false => day.run,
+    };
+
+    if let Err(err) = input
+        .map_err(Box::from)
+        .and_then(|input| run_day(run, options.part, &input, options.time))
+    {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,150 @@
+use aoc::days::{find, Day};
+use std::{
+    collections::BTreeMap,
+    fs::{read_dir, read_to_string},
+    path::PathBuf,
+};
+
+type Expected = BTreeMap<String, BTreeMap<String, String>>;
+
+// Reads the small subset of TOML used by the `expected.toml` sidecars: one
+// table per example file (named after the file stem) with `partN = answer`.
+fn parse_expected(content: &str) -> Expected {
+    let mut expected = Expected::new();
+    let mut current = None;
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(table) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            current = Some(table.trim().to_string());
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .unwrap_or_else(|| panic!("Line {}: expected `key = value`", number + 1));
+        let table = current
+            .clone()
+            .unwrap_or_else(|| panic!("Line {}: answer outside of a table", number + 1));
+        let value = value.trim().trim_matches('"').to_string();
+
+        expected
+            .entry(table)
+            .or_default()
+            .insert(key.trim().to_string(), value);
+    }
+
+    expected
+}
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn day_dir(day: &Day) -> PathBuf {
+    root()
+        .join(day.year.to_string())
+        .join(format!("day{:02}", day.day))
+}
+
+// Every `<year>/dayNN` directory of the workspace with an `expected.toml`.
+fn sidecars() -> Vec<(u16, u8)> {
+    let mut sidecars = Vec::new();
+
+    for year in read_dir(root()).unwrap().flatten() {
+        let Ok(number) = year.file_name().to_string_lossy().parse::<u16>() else {
+            continue;
+        };
+        for day in read_dir(year.path()).unwrap().flatten() {
+            let name = day.file_name().to_string_lossy().into_owned();
+            let Some(Ok(day_number)) = name.strip_prefix("day").map(str::parse::<u8>) else {
+                continue;
+            };
+            if day.path().join("expected.toml").exists() {
+                sidecars.push((number, day_number));
+            }
+        }
+    }
+
+    sidecars.sort_unstable();
+    sidecars
+}
+
+// Runs every example of `year`/`day` that has an answer for `part`.
+fn check(year: u16, day: u8, part: u8) {
+    let day = find(year, day).unwrap();
+    let dir = day_dir(day);
+    let content = read_to_string(dir.join("expected.toml"))
+        .unwrap_or_else(|err| panic!("Unable to read {}: {err}", dir.display()));
+    let key = format!("part{part}");
+
+    let mut checked = 0;
+    for (example, answers) in parse_expected(&content) {
+        if let Some(unknown) = answers
+            .keys()
+            .find(|key| !["part1", "part2"].contains(&key.as_str()))
+        {
+            panic!("{}: unknown answer `{unknown}`", dir.display());
+        }
+        let Some(answer) = answers.get(&key) else {
+            continue;
+        };
+
+        let path = dir.join(format!("{example}.txt"));
+        let input = read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Unable to read {}: {err}", path.display()));
+        let run = (day.example)(&input, &[part])
+            .unwrap_or_else(|err| panic!("Unable to solve {}: {err}", path.display()));
+
+        assert_eq!(
+            &run.answers[0].value,
+            answer,
+            "{} (part {part})",
+            path.display()
+        );
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "{}: no examples with answers for part {part}",
+        dir.display()
+    );
+}
+
+#[test]
+fn every_sidecar_belongs_to_a_day() {
+    for (year, day) in sidecars() {
+        assert!(
+            find(year, day).is_some(),
+            "{year} day {day} has examples, but no registered solution"
+        );
+    }
+}
+
+macro_rules! example_tests {
+    ($($name:ident: $year:literal, $day:literal, $($solution:ty),+;)+) => {
+        $(
+            mod $name {
+                #[test]
+                fn part1() {
+                    super::check($year, $day, 1);
+                }
+
+                #[test]
+                fn part2() {
+                    super::check($year, $day, 2);
+                }
+            }
+        )+
+    };
+}
+
+aoc::for_each_day!(example_tests);